@@ -0,0 +1,74 @@
+use std::collections;
+use std::cmp::Reverse;
+use anyhow::Context;
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, anyhow::Error> {
+        let mut sums: Vec<i32> = Vec::new();
+        let mut sum = 0;
+        for line in input.lines() {
+            if line.is_empty() {
+                sums.push(sum);
+                sum = 0;
+            } else {
+                sum += line.parse::<i32>()?;
+            }
+        }
+        if sum != 0 {
+            sums.push(sum);
+        }
+        Ok(sums)
+    }
+
+    fn part1(&self, sums: &Vec<i32>) -> Result<Answer, anyhow::Error> {
+        Ok((*sums.iter().max().context("no elves")?).into())
+    }
+
+    fn part2(&self, sums: &Vec<i32>) -> Result<Answer, anyhow::Error> {
+        let mut heap = collections::BinaryHeap::new();
+        for &sum in sums {
+            heap.push(Reverse(sum));
+            if heap.len() > 3 {
+                heap.pop();
+            }
+        }
+
+        Ok((heap.pop().context("1")?.0+heap.pop().context("2")?.0+heap.pop().context("3")?.0).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 24000.into());
+        assert_eq!(Day.part2(&input).unwrap(), 45000.into());
+    }
+}
@@ -0,0 +1,299 @@
+use anyhow::{Result, Ok, bail};
+use lib::solution::{Answer, Solution};
+
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    fn run(&self) -> ProgramRun<'_> {
+        ProgramRun {
+            program: self,
+            cycle: 0,
+            instruction_pointer: 0,
+            reg_x: 1,
+            remaining_to_execute: 0,
+        }
+    }
+
+    fn cycles(&self) -> usize {
+        self.instructions.iter().map(|instruction| match instruction {
+            Instruction::AddX(_) => 2,
+            Instruction::Noop => 1,
+        }).sum()
+    }
+}
+
+struct ProgramRun<'a> {
+    program: &'a Program,
+    cycle: u64,
+    instruction_pointer: u64,
+    reg_x: i64,
+    remaining_to_execute: u8,
+}
+
+impl<'a> Iterator for ProgramRun<'a> {
+    type Item = (u64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cycle += 1;
+        if self.remaining_to_execute > 0 {
+            self.remaining_to_execute -= 1;
+            return match self.program.instructions[self.instruction_pointer as usize - 1] {
+                Instruction::AddX(v) => {
+                    let old_reg_x = self.reg_x;
+                    self.reg_x += v;
+                    Some((self.cycle, old_reg_x))
+                }
+                _ => unreachable!(),
+            };
+        }
+        self.instruction_pointer += 1;
+        if self.instruction_pointer > self.program.instructions.len() as u64 {
+            return None;
+        }
+        match self.program.instructions[self.instruction_pointer as usize - 1] {
+            Instruction::Noop => {}
+            Instruction::AddX(_) => {
+                self.remaining_to_execute = 1;
+            }
+        }
+        Some((self.cycle, self.reg_x))
+    }
+}
+
+enum Instruction {
+    AddX(i64),
+    Noop,
+}
+
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Program;
+
+    fn parse(&self, input: &str) -> Result<Program> {
+        parse(input)
+    }
+
+    fn part1(&self, program: &Program) -> Result<Answer> {
+        let mut sum = 0;
+        for (cycle, reg_x) in program.run() {
+            if cycle == 20 || (cycle > 20 && (cycle - 20) % 40 == 0) {
+                sum += cycle as i64 * reg_x
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, program: &Program) -> Result<Answer> {
+        let mut screen = String::new();
+        for (cycle, reg_x) in program.run() {
+            screen.push(pixel(cycle, reg_x));
+            if cycle % 40 == 0 && (cycle as usize) < program.cycles() {
+                screen.push('\n');
+            }
+        }
+        Ok(screen.into())
+    }
+}
+
+fn pixel(cycle: u64, reg_x: i64) -> char {
+    // cycles are counted from 1, columns from 0
+    let column = ((cycle - 1) % 40) as i64;
+    if (column - reg_x).abs() <= 1 {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+fn parse(input: &str) -> Result<Program> {
+    Ok(
+        Program {
+            instructions: input
+                .lines()
+                .map(|line| {
+                    let split = line.split_whitespace().collect::<Vec<&str>>();
+                    Ok(match split.as_slice() {
+                        ["noop"] => Instruction::Noop,
+                        ["addx", v] => Instruction::AddX(v.parse::<i64>()?),
+                        _ => bail!("invalid operation")
+                    })
+                })
+                .collect::<Result<_>>()?
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+";
+
+    const EXAMPLE_SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 13140.into());
+        assert_eq!(Day.part2(&input).unwrap(), EXAMPLE_SCREEN.into());
+    }
+}
@@ -1,8 +1,8 @@
 use anyhow::{bail, Context, Ok, Result};
 use once_cell::sync::Lazy;
+use lib::math;
+use lib::solution::{Answer, Solution};
 use regex::Regex;
-use std::io;
-use std::io::Read;
 
 static RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(concat!(
@@ -16,12 +16,14 @@ static RE: Lazy<Regex> = Lazy::new(|| {
     .unwrap()
 });
 
-struct Monkey {
+#[derive(Clone)]
+pub struct Monkey {
     items: Vec<u64>,
     monkey_rule: MonkeyRule,
     inspect_count: u64,
 }
 
+#[derive(Clone)]
 struct MonkeyRule {
     operation: Operation,
     divisible_by: u64,
@@ -29,6 +31,7 @@ struct MonkeyRule {
     target_false: u64,
 }
 
+#[derive(Clone)]
 struct Operation {
     operation: OperationType,
     value: Operand,
@@ -37,45 +40,59 @@ struct Operation {
 impl Operation {
     fn inspect(&self, item: u64) -> u64 {
         let operand = match self.value {
-            Operand::OLD => item,
-            Operand::VALUE(x) => x,
+            Operand::Old => item,
+            Operand::Value(x) => x,
         };
         match self.operation {
-            OperationType::ADD => item + operand,
-            OperationType::MUL => item * operand,
+            OperationType::Add => item + operand,
+            OperationType::Mul => item * operand,
         }
     }
 }
 
+#[derive(Clone)]
 enum Operand {
-    OLD,
-    VALUE(u64),
+    Old,
+    Value(u64),
 }
 
+#[derive(Clone)]
 enum OperationType {
-    ADD,
-    MUL,
+    Add,
+    Mul,
 }
 
-fn main() -> Result<()> {
-    let mut monkeys = parse()?;
+#[derive(Default)]
+pub struct Day;
 
-    let mut lcm = 1;
-    for monkey in monkeys.iter() {
-        lcm *= monkey.monkey_rule.divisible_by;
+impl Solution for Day {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Monkey>> {
+        parse(input)
+    }
+
+    fn part1(&self, monkeys: &Vec<Monkey>) -> Result<Answer> {
+        Ok(monkey_business(monkeys.clone(), 20, |item| item / 3).into())
     }
 
-    for _ in 0..10000 {
+    fn part2(&self, monkeys: &Vec<Monkey>) -> Result<Answer> {
+        let lcm = math::lcm_all(monkeys.iter().map(|monkey| monkey.monkey_rule.divisible_by))
+            .context("divisors overflow")?;
+
+        Ok(monkey_business(monkeys.clone(), 10000, |item| item % lcm).into())
+    }
+}
+
+fn monkey_business(mut monkeys: Vec<Monkey>, rounds: usize, relief: impl Fn(u64) -> u64) -> u64 {
+    for _ in 0..rounds {
         for i in 0..monkeys.len() {
             while !monkeys[i].items.is_empty() {
                 let monkey = &mut monkeys[i];
                 let mut item = monkey.items.remove(0);
                 item = monkey.monkey_rule.operation.inspect(item);
                 monkey.inspect_count += 1;
-                //part 1
-                // item /= 3;
-                //part 2
-                item %= lcm;
+                item = relief(item);
                 let target_i = match item % monkey.monkey_rule.divisible_by {
                     0 => monkey.monkey_rule.target_true,
                     _ => monkey.monkey_rule.target_false,
@@ -88,19 +105,13 @@ fn main() -> Result<()> {
     monkeys.sort_by_key(|m| m.inspect_count);
     monkeys.reverse();
 
-    println!("{}", monkeys[0].inspect_count * monkeys[1].inspect_count);
-
-    Ok(())
+    monkeys[0].inspect_count * monkeys[1].inspect_count
 }
 
-fn parse() -> Result<Vec<Monkey>> {
-    let mut buf: String = Default::default();
-    io::stdin().read_to_string(&mut buf)?;
-
+fn parse(input: &str) -> Result<Vec<Monkey>> {
     Ok(RE
-        .captures_iter(&buf)
+        .captures_iter(input)
         .map(|capture| {
-            println!("capture {}", capture.get(0).unwrap().as_str());
             Ok(Monkey {
                 items: capture
                     .get(1)
@@ -112,13 +123,13 @@ fn parse() -> Result<Vec<Monkey>> {
                 monkey_rule: MonkeyRule {
                     operation: Operation {
                         operation: match capture.get(2).context("capture group 2")?.as_str() {
-                            "+" => OperationType::ADD,
-                            "*" => OperationType::MUL,
+                            "+" => OperationType::Add,
+                            "*" => OperationType::Mul,
                             s => bail!("unknown operand {s}"),
                         },
                         value: match capture.get(3).context("capture group 3")?.as_str() {
-                            "old" => Operand::OLD,
-                            s => Operand::VALUE(s.parse()?),
+                            "old" => Operand::Old,
+                            s => Operand::Value(s.parse()?),
                         },
                     },
                     divisible_by: capture
@@ -142,3 +153,46 @@ fn parse() -> Result<Vec<Monkey>> {
         })
         .collect::<Result<Vec<_>>>()?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 10605.into());
+        assert_eq!(Day.part2(&input).unwrap(), 2713310158_u64.into());
+    }
+}
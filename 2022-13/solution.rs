@@ -1,13 +1,13 @@
 use anyhow::{bail, Context, Ok, Result};
 use lib::str::StrExt;
+use lib::solution::{Answer, Solution};
 use std::cmp::{Ordering, PartialOrd};
-use std::io::stdin;
 use std::str::FromStr;
 
 #[derive(Debug)]
-enum Value {
+pub enum Value {
     Int(u64),
-    List(Vec<Box<Value>>),
+    List(Vec<Value>),
 }
 
 impl Default for Value {
@@ -32,7 +32,7 @@ impl Value {
         }
         match s.char_at(0) {
             '[' => {
-                let mut list: Vec<Box<Value>> = vec![];
+                let mut list: Vec<Value> = vec![];
                 let mut start_i = 1;
                 loop {
                     if s.char_at(start_i) == ']' {
@@ -43,7 +43,7 @@ impl Value {
                     if start_i + len >= s.len() {
                         bail!("list not terminated");
                     }
-                    list.push(Box::new(value));
+                    list.push(value);
                     match s.char_at(start_i + len) {
                         ']' => return Ok((Value::List(list), start_i + len + 1)),
                         ',' => {
@@ -89,10 +89,10 @@ impl Ord for Value {
             (Value::Int(lhs), Value::Int(rhs)) => lhs.cmp(rhs),
             (Value::List(lhs), Value::List(rhs)) => lhs.cmp(rhs),
             (Value::Int(lhs), Value::List(_)) => {
-                Value::List(vec![Box::new(Value::Int(*lhs))]).cmp(other)
+                Value::List(vec![Value::Int(*lhs)]).cmp(other)
             }
             (Value::List(_), Value::Int(rhs)) => {
-                self.cmp(&Value::List(vec![Box::new(Value::Int(*rhs))]))
+                self.cmp(&Value::List(vec![Value::Int(*rhs)]))
             }
         }
     }
@@ -110,16 +110,26 @@ impl PartialOrd for Value {
     }
 }
 
-fn main() -> Result<()> {
-    let pairs = parse()?;
+#[derive(Default)]
+pub struct Day;
 
-    println!("{}", part1(&pairs));
-    println!("{}", part2(&pairs));
+impl Solution for Day {
+    type Input = Vec<[Value; 2]>;
 
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Vec<[Value; 2]>> {
+        parse(input)
+    }
+
+    fn part1(&self, pairs: &Vec<[Value; 2]>) -> Result<Answer> {
+        Ok(part1(pairs).into())
+    }
+
+    fn part2(&self, pairs: &Vec<[Value; 2]>) -> Result<Answer> {
+        Ok(part2(pairs).into())
+    }
 }
 
-fn part1(pairs: &Vec<[Value; 2]>) -> u64 {
+fn part1(pairs: &[[Value; 2]]) -> u64 {
     pairs
         .iter()
         .enumerate()
@@ -134,7 +144,7 @@ enum Marker {
     Divider,
 }
 
-fn part2(pairs: &Vec<[Value; 2]>) -> u64 {
+fn part2(pairs: &[[Value; 2]]) -> u64 {
     let all_pairs: Vec<_> = pairs.iter().flatten().collect();
 
     let mut marked_pairs: Vec<_> = all_pairs.iter().map(|x| (Marker::Original, x)).collect();
@@ -152,15 +162,14 @@ fn part2(pairs: &Vec<[Value; 2]>) -> u64 {
         .product::<usize>() as u64
 }
 
-fn parse() -> Result<Vec<[Value; 2]>> {
+fn parse(input: &str) -> Result<Vec<[Value; 2]>> {
     let mut result: Vec<[Value; 2]> = vec![];
     let mut pair: [Value; 2] = Default::default();
 
     let mut i = 0;
-    for line in stdin().lines() {
-        let line = line?;
+    for line in input.lines() {
         if i >= 2 {
-            if line == "" {
+            if line.is_empty() {
                 result.push(pair);
                 pair = Default::default();
                 i = 0;
@@ -178,3 +187,42 @@ fn parse() -> Result<Vec<[Value; 2]>> {
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 13.into());
+        assert_eq!(Day.part2(&input).unwrap(), 140.into());
+    }
+}
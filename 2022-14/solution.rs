@@ -0,0 +1,177 @@
+use anyhow::{Context, Ok, Result};
+use lib::grid::{Coord, Offset, SparseGrid};
+use lib::grid_tile;
+use lib::solution::{Answer, Solution};
+
+pub struct Span {
+    from: Coord,
+    to: Coord,
+}
+
+#[derive(Clone)]
+enum Material {
+    Air,
+    Rock,
+    Sand,
+}
+
+grid_tile!(Material {
+    '.' => Material::Air,
+    '#' => Material::Rock,
+    'o' => Material::Sand,
+});
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Span>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Span>> {
+        parse(input)
+    }
+
+    fn part1(&self, spans: &Vec<Span>) -> Result<Answer> {
+        let mut solids = spans_to_solids(spans);
+
+        let lowest = solids.bounds().context("empty scan")?.max.y;
+
+        let spawn = Coord { x: 500, y: 0 };
+
+        let mut i = 0;
+        loop {
+            let rest_at = drop_till_rest(&solids, spawn, lowest+2);
+            if rest_at.y > lowest {
+                break
+            }
+            solids.insert(rest_at, Material::Sand);
+            i += 1;
+        }
+
+        Ok(i.into())
+    }
+
+    fn part2(&self, spans: &Vec<Span>) -> Result<Answer> {
+        let mut solids = spans_to_solids(spans);
+
+        let lowest = solids.bounds().context("empty scan")?.max.y;
+
+        let spawn = Coord { x: 500, y: 0 };
+
+        let mut i = 0;
+        loop {
+            let rest_at = drop_till_rest(&solids, spawn, lowest+2);
+            solids.insert(rest_at, Material::Sand);
+            i += 1;
+            if rest_at == spawn {
+                break
+            }
+        }
+
+        Ok(i.into())
+    }
+}
+
+static OFFSETS_ATTEMPTS: &[Offset] = &[
+    Offset { x: 0, y: 1 },
+    Offset { x: -1, y: 1 },
+    Offset { x: 1, y: 1 },
+];
+
+fn drop_till_rest(solids: &SparseGrid<Material>, start: Coord, lowest: i64) -> Coord {
+    let mut current = start;
+    'outer: loop {
+        for &offset in OFFSETS_ATTEMPTS {
+            let moved = current + offset;
+            if !matches!(solids.get(moved).unwrap_or(&Material::Air), Material::Air) {
+                continue;
+            }
+            if moved.y >= lowest {
+                return current
+            }
+            current = moved;
+            continue 'outer;
+        }
+        return current;
+    }
+}
+
+fn spans_to_solids(spans: &Vec<Span>) -> SparseGrid<Material> {
+    let mut result = SparseGrid::new();
+    for span in spans {
+        let mut current = span.from;
+        let dif = span.to - span.from;
+        let direction = Offset {
+            x: sign(dif.x),
+            y: sign(dif.y),
+        };
+        result.insert(current, Material::Rock);
+        while current != span.to {
+            current = current + direction;
+            result.insert(current, Material::Rock);
+        }
+    }
+
+    result
+}
+
+fn sign(v: i64) -> i64 {
+    if v > 0 {
+        1
+    } else if v < 0 {
+        -1
+    } else {
+        0
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Span>> {
+    input
+        .lines()
+        .map(|line| {
+            Ok(line
+                .split(" -> ")
+                .map(|s| {
+                    let mut splits = s.split(',');
+                    Ok(Coord {
+                        x: splits
+                            .next()
+                            .context("no first coordinate")?
+                            .parse::<i64>()?,
+                        y: splits
+                            .next()
+                            .context("no second coordinate")?
+                            .parse::<i64>()?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?
+                .windows(2)
+                .map(|window| Span {
+                    from: window[0],
+                    to: window[1],
+                })
+                .collect::<Vec<_>>())
+        })
+        .try_fold::<_, _, Result<Vec<Span>>>(vec![], |mut acc, item| {
+            acc.append(&mut item?);
+            Ok(acc)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 24.into());
+        assert_eq!(Day.part2(&input).unwrap(), 93.into());
+    }
+}
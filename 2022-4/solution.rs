@@ -0,0 +1,82 @@
+use anyhow::Error;
+use lib::solution::{Answer, Solution};
+
+type Range = (i64, i64);
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, anyhow::Error> {
+        input.lines().map(|line| {
+            let pair = parse_pair(line)?;
+            Ok((parse_range(pair.0)?, parse_range(pair.1)?))
+        }).collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, anyhow::Error> {
+        let mut sum = 0;
+        for (range1, range2) in input {
+            if (range1.0 >= range2.0 && range1.1 <= range2.1)
+                || (range2.0 >= range1.0 && range2.1 <= range1.1) {
+                sum += 1;
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, anyhow::Error> {
+        let mut sum = 0;
+        for (range1, range2) in input {
+            if (range1.0 >= range2.0 && range1.0 <= range2.1)
+                 || (range1.1 >= range2.0 && range1.1 <= range2.1)
+                 || (range2.0 >= range1.0 && range2.0 <= range1.1)
+                 || (range2.1 >= range1.0 && range2.1 <= range1.1) {
+                sum += 1;
+            }
+        }
+        Ok(sum.into())
+    }
+}
+
+fn parse_pair(s: &str) -> Result<(&str, &str), Error> {
+    let res: Vec<&str> = s.split(',').collect();
+    if res.len() != 2 {
+        return Err(Error::msg("not 1 comma"))
+    }
+
+    Ok((res[0], res[1]))
+}
+
+fn parse_range(s: &str) -> Result<Range, Error> {
+    let res: Vec<&str> = s.split('-').collect();
+    if res.len() != 2 {
+        return Err(Error::msg("not 1 comma"))
+    }
+
+    Ok((res[0].parse::<i64>()?, res[1].parse::<i64>()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 2.into());
+        assert_eq!(Day.part2(&input).unwrap(), 4.into());
+    }
+}
@@ -0,0 +1,145 @@
+use std::ops::{Deref};
+use anyhow::{Context, Error};
+use lib::solution::{Answer, Solution};
+use once_cell::sync::Lazy;
+use regex::{Regex};
+
+static RE2: Lazy<Regex> = Lazy::new(|| Regex::new(r#"move (\d+) from (\d+) to (\d+)"#).unwrap());
+
+pub struct Puzzle {
+    stacks: Vec<Vec<String>>,
+    moves: Vec<[i64; 3]>,
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle, Error> {
+        let mut parse_state = 0;
+        let mut stacks: Vec<Vec<String>> = Vec::new();
+        let mut moves = Vec::new();
+        for line in input.lines() {
+            match parse_state {
+                0 => {
+                    if line.starts_with(" 1") {
+                        parse_state = 1;
+                        stacks.iter_mut().for_each(|stack| { stack.reverse() });
+                        continue;
+                    }
+                    let row = parse_stack_row(line)?;
+                    if row.len() > stacks.len() {
+                        stacks.resize_with(row.len(), Vec::new);
+                    }
+                    for (i, item) in row.iter().enumerate() {
+                        match item {
+                            None => {}
+                            Some(a) => stacks[i].push(a.to_string())
+                        }
+                    }
+                }
+                1 => {
+                    parse_state = 2;
+                }
+                2 => {
+                    moves.push(parse_move(line)?);
+                }
+                _ => {
+                    continue;
+                }
+            }
+        }
+
+        Ok(Puzzle { stacks, moves })
+    }
+
+    fn part1(&self, input: &Puzzle) -> Result<Answer, Error> {
+        let mut stacks = input.stacks.clone();
+        for mov in &input.moves {
+            for _ in 0..(mov[0] as usize) {
+                let popped = stacks[mov[1] as usize - 1usize].pop().context("stack empty")?;
+                stacks[mov[2] as usize - 1usize].push(popped);
+            }
+        }
+        Ok(top_crates(&stacks).into())
+    }
+
+    fn part2(&self, input: &Puzzle) -> Result<Answer, Error> {
+        let mut stacks = input.stacks.clone();
+        for mov in &input.moves {
+            let amount = mov[0] as usize;
+            let from_stack = &mut stacks[mov[1] as usize - 1usize];
+            let copy = Vec::from_iter(from_stack.drain(from_stack.len() - amount..));
+            stacks[mov[2] as usize - 1usize].extend_from_slice(copy.as_slice());
+        }
+        Ok(top_crates(&stacks).into())
+    }
+}
+
+fn top_crates(stacks: &[Vec<String>]) -> String {
+    stacks.iter().map(|x| x.last().map(String::deref).unwrap_or(" ")).collect()
+}
+
+fn parse_stack_row(s: &str) -> Result<Vec<Option<String>>, Error> {
+    let mut res: Vec<Option<String>> = Vec::default();
+    let mut spaces = 0;
+    for c in s.chars() {
+        match c {
+            '[' => {
+                spaces = 0;
+            }
+            ']' => {}
+            x if ('A'..='z').contains(&c) => {
+                res.push(Some(x.into()))
+            }
+            ' ' => {
+                spaces += 1;
+                if spaces >= 4 {
+                    spaces = 0;
+                    res.push(None)
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(res)
+}
+
+fn parse_move(s: &str) -> Result<[i64; 3], Error> {
+    let captures = RE2.captures(s).context("no match")?;
+    if captures.len() != 4 {
+        return Err(Error::msg("not 3 captured groups"));
+    }
+
+    let f = |i| -> Result<i64, Error> {
+        captures.get(i).context("no match")?.as_str().parse::<i64>().context("parse int")
+    };
+
+    Ok([f(1)?, f(2)?, f(3)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), "CMZ".into());
+        assert_eq!(Day.part2(&input).unwrap(), "MCD".into());
+    }
+}
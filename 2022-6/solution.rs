@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+use anyhow::Error;
+use lib::solution::{Answer, Solution};
+use ringbuffer::{ConstGenericRingBuffer, RingBuffer};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String, Error> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(&self, input: &String) -> Result<Answer, Error> {
+        Ok(marker_end::<4>(input)?.into())
+    }
+
+    fn part2(&self, input: &String) -> Result<Answer, Error> {
+        Ok(marker_end::<14>(input)?.into())
+    }
+}
+
+fn marker_end<const SIZE: usize>(input: &str) -> Result<usize, Error> {
+    let mut buf: ConstGenericRingBuffer<char, SIZE> = ConstGenericRingBuffer::new();
+    for (i, c) in input.chars().enumerate() {
+        buf.push(c);
+        let mut set = HashSet::new();
+        if buf.len() < SIZE {
+            continue
+        }
+        let mut duplicate = false;
+        for c in &buf {
+            if !set.insert(c) {
+                duplicate = true;
+                break
+            }
+        }
+        if !duplicate {
+            return Ok(i+1);
+        }
+    }
+    Err(Error::msg("not found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19)]
+    #[case("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23)]
+    #[case("nppdvjthqldpwncqszvftbrmjlhg", 6, 23)]
+    #[case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29)]
+    #[case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26)]
+    fn test_example(#[case] example: &str, #[case] expected_part1: u64, #[case] expected_part2: u64) {
+        let input = Day.parse(example).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), expected_part1.into());
+        assert_eq!(Day.part2(&input).unwrap(), expected_part2.into());
+    }
+}
@@ -1,11 +1,11 @@
 use std::collections::hash_map::{Values};
 use std::collections::HashMap;
-use std::io;
 use std::ptr::NonNull;
 use anyhow::{Context, Error};
+use lib::solution::{Answer, Solution};
 
 #[derive(Default, Debug)]
-struct FileSystem {
+pub struct FileSystem {
     root: Node,
 }
 
@@ -20,25 +20,12 @@ struct Dict {
     content: HashMap<String, Node>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct Node {
-    // always points to the key of hashmap this Node is the value of
-    // and to constant empty string for root
-    name: NonNull<str>,
     type_: NodeType,
     parent: Option<NonNull<Node>>,
 }
 
-impl Default for Node {
-    fn default() -> Self {
-        Node {
-            name: "".into(),
-            type_: Default::default(),
-            parent: None,
-        }
-    }
-}
-
 #[derive(Debug)]
 enum NodeType {
     File(File),
@@ -92,82 +79,82 @@ struct FileListing<'a> {
     size: u64,
 }
 
-fn main() -> Result<(), Error> {
-    let stdin = io::stdin();
+#[derive(Default)]
+pub struct Day;
 
-    let mut file_system: FileSystem = Default::default();
-    let mut state: State = State {
-        current_directory: NonNull::new(&mut file_system.root).context("null ptr when initializing current directory")?,
-        executing: StateType::None,
-    };
-    for line in stdin.lines() {
-        let line = line?;
+impl Solution for Day {
+    type Input = FileSystem;
 
-        let parse_line = parse_line(&line)?;
+    fn parse(&self, input: &str) -> Result<FileSystem, Error> {
+        let mut file_system: FileSystem = Default::default();
+        let mut state: State = State {
+            current_directory: NonNull::new(&mut file_system.root).context("null ptr when initializing current directory")?,
+            executing: StateType::None,
+        };
+        for line in input.lines() {
+            let parse_line = parse_line(line)?;
 
-        // println!("line: {:?}", parse_line);
-        execute(&parse_line, &mut state, &mut file_system)?;
+            execute(&parse_line, &mut state, &mut file_system)?;
+        }
 
-        // println!("state: {:?}", state);
-        // println!("fs: {:?}", file_system);
-    }
+        calculate_containing_size(&mut file_system.root)?;
 
-    let max_used_size = 40_000_000; // inclusive
-    let root_size = calculate_containing_size(&mut file_system.root)?;
-    if root_size <= max_used_size {
-        return Err(Error::msg("already enough space"));
+        Ok(file_system)
     }
-    let need_to_free_up = root_size - max_used_size;
-
-    //part1
-    // let mut sum = 0;
-    // for node in find(&file_system.root, |node| {
-    //     match &node.type_ {
-    //         NodeType::Dict(dict) => {
-    //             dict.nested_size.unwrap() <= 100000
-    //         }
-    //         _ => false
-    //     }
-    // }) {
-    //     match &node.type_ {
-    //         NodeType::Dict(dict) => {
-    //             sum += dict.nested_size.unwrap()
-    //         }
-    //         _ => {}
-    //     }
-    // }
-    // println!("{}", sum);
-
-    //part2
-    let mut min: Option<u64> = None;
-    for node in find(&file_system.root, |node| {
-        match &node.type_ {
-            NodeType::Dict(dict) => {
-                let dict_size = dict.nested_size.unwrap();
-                dict_size >= need_to_free_up
+
+    fn part1(&self, file_system: &FileSystem) -> Result<Answer, Error> {
+        let mut sum = 0;
+        for node in find(&file_system.root, |node| {
+            match &node.type_ {
+                NodeType::Dict(dict) => {
+                    dict.nested_size.unwrap() <= 100000
+                }
+                _ => false
             }
-            _ => false
+        }) {
+            let NodeType::Dict(dict) = &node.type_ else { unreachable!() };
+            sum += dict.nested_size.context("uninitialized dict size")?
         }
-    }) {
-        let NodeType::Dict(dict) = &node.type_ else { unreachable!() };
-        match min {
-            None => {
-                min = Some(dict.nested_size.context("uninitialized dict size")?)
+        Ok(sum.into())
+    }
+
+    fn part2(&self, file_system: &FileSystem) -> Result<Answer, Error> {
+        let max_used_size = 40_000_000; // inclusive
+        let NodeType::Dict(root) = &file_system.root.type_ else { unreachable!() };
+        let root_size = root.nested_size.context("uninitialized dict size")?;
+        if root_size <= max_used_size {
+            return Err(Error::msg("already enough space"));
+        }
+        let need_to_free_up = root_size - max_used_size;
+
+        let mut min: Option<u64> = None;
+        for node in find(&file_system.root, |node| {
+            match &node.type_ {
+                NodeType::Dict(dict) => {
+                    let dict_size = dict.nested_size.unwrap();
+                    dict_size >= need_to_free_up
+                }
+                _ => false
             }
-            Some(previous_min) => {
-                let dict_size = dict.nested_size.context("uninitialized dict size")?;
-                if previous_min > dict_size {
-                    min = Some(dict_size)
+        }) {
+            let NodeType::Dict(dict) = &node.type_ else { unreachable!() };
+            match min {
+                None => {
+                    min = Some(dict.nested_size.context("uninitialized dict size")?)
+                }
+                Some(previous_min) => {
+                    let dict_size = dict.nested_size.context("uninitialized dict size")?;
+                    if previous_min > dict_size {
+                        min = Some(dict_size)
+                    }
                 }
             }
         }
+        Ok(min.context("no suitable directory found")?.into())
     }
-    println!("{}", min.context("no suitable directory found")?);
-
-    Ok(())
 }
 
-fn find<F>(root: &Node, predicate: F) -> NodeIter<F>
+fn find<F>(root: &Node, predicate: F) -> NodeIter<'_, F>
     where
         F: Fn(&Node) -> bool
 {
@@ -222,7 +209,6 @@ fn execute(parsed_line: &ParsedLine, state: &mut State, file_system: &mut FileSy
                                         None => {
                                             let child_target_string = child_target.to_string();
                                             let node = Node {
-                                                name: child_target_string.as_str().into(),
                                                 type_: NodeType::Dict(Default::default()),
                                                 parent: Some(state.current_directory),
                                             };
@@ -262,7 +248,6 @@ fn execute(parsed_line: &ParsedLine, state: &mut State, file_system: &mut FileSy
                         ParsedLineListing::FileListing(file_listing) => {
                             let name = file_listing.name.to_string();
                             let node = Node {
-                                name: name.as_str().into(),
                                 type_: NodeType::File(File {
                                     size: file_listing.size
                                 }),
@@ -273,7 +258,6 @@ fn execute(parsed_line: &ParsedLine, state: &mut State, file_system: &mut FileSy
                         ParsedLineListing::DictListing(dict_listing) => {
                             let name = dict_listing.to_string();
                             let node = Node {
-                                name: name.as_str().into(),
                                 type_: NodeType::Dict(Default::default()),
                                 parent: Some(state.current_directory),
                             };
@@ -290,7 +274,7 @@ fn execute(parsed_line: &ParsedLine, state: &mut State, file_system: &mut FileSy
     Ok(())
 }
 
-fn parse_line(line: &str) -> Result<ParsedLine, Error> {
+fn parse_line(line: &str) -> Result<ParsedLine<'_>, Error> {
     let stripped_command = line.strip_prefix("$ ");
     match stripped_command {
         Some(command) => {
@@ -354,26 +338,23 @@ impl<'a, F> Iterator for NodeIter<'a, F>
     type Item = &'a Node;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.root {
-            Some(root) => {
-                self.root = None;
-                let node = match &root.type_ {
-                    NodeType::File(_) => {
-                        root
-                    }
-                    NodeType::Dict(dict) => {
-                        self.iterator_stack.push(dict.content.values());
-                        root
-                    }
-                };
-                if (self.predicate)(node) {
-                    return Some(node);
+        if let Some(root) = self.root {
+            self.root = None;
+            let node = match &root.type_ {
+                NodeType::File(_) => {
+                    root
                 }
+                NodeType::Dict(dict) => {
+                    self.iterator_stack.push(dict.content.values());
+                    root
+                }
+            };
+            if (self.predicate)(node) {
+                return Some(node);
             }
-            None => {}
         }
 
-        let ref mut stack = self.iterator_stack;
+        let stack = &mut self.iterator_stack;
         loop {
             match stack.last_mut() {
                 None => {
@@ -408,3 +389,42 @@ impl<'a, F> Iterator for NodeIter<'a, F>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 95437.into());
+        assert_eq!(Day.part2(&input).unwrap(), 24933642.into());
+    }
+}
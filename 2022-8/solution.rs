@@ -1,10 +1,10 @@
 use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
-use std::io;
 use anyhow::Error;
+use lib::solution::{Answer, Solution};
 
 #[derive(Default)]
-struct State {
+pub struct State {
     state: Vec<Vec<u8>>,
     rows: u64,
     columns: u64,
@@ -38,7 +38,7 @@ struct LineOfSight {
 }
 
 impl State {
-    fn iter(&self, pov: PointOfView) -> PointOfViewIter {
+    fn iter(&self, pov: PointOfView) -> PointOfViewIter<'_> {
         PointOfViewIter {
             state: self,
             pov,
@@ -47,8 +47,9 @@ impl State {
     }
 
     // exclusive of target tree
-    fn los_iter_from_tree(&self, tree: TreeCoordinates, pov: PointOfView) -> LineOfSightIter {
-        let res = LineOfSightIter {
+    fn los_iter_from_tree(&self, tree: TreeCoordinates, pov: PointOfView) -> LineOfSightIter<'_> {
+        
+        LineOfSightIter {
             state: self,
             los: LineOfSight {
                 pov,
@@ -65,8 +66,7 @@ impl State {
                 PointOfView::Bottom => self.rows - tree.row,
                 PointOfView::Right => self.columns - tree.column,
             },
-        };
-        res
+        }
     }
 }
 
@@ -170,60 +170,64 @@ impl<'a> Iterator for LineOfSightIter<'a> {
     }
 }
 
-fn main() -> Result<(), Error> {
-    let stdin = io::stdin();
-    let mut state: State = Default::default();
-    for line in stdin.lines() {
-        let line = line?; // satisfy borrow checker - live for whole loop iteration
-        let line_bytes = line.as_bytes();
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = State;
 
-        parse_line(line_bytes, &mut state)
+    fn parse(&self, input: &str) -> Result<State, Error> {
+        let mut state: State = Default::default();
+        for line in input.lines() {
+            parse_line(line.as_bytes(), &mut state)
+        }
+        Ok(state)
     }
 
-    //part 1
-    let mut visible_trees = HashSet::<TreeCoordinates>::new();
-
-    for pov in POINT_OF_VIEWS {
-        for tree_line in state.iter(pov) {
-            let mut visible_line: Option<u8> = None;
-            for tree in tree_line {
-                if match visible_line {
-                    None => true,
-                    Some(visible_line) => tree.height > visible_line
-                } {
-                    visible_trees.insert(tree.coords);
-                    visible_line = Some(tree.height)
+    fn part1(&self, state: &State) -> Result<Answer, Error> {
+        let mut visible_trees = HashSet::<TreeCoordinates>::new();
+
+        for pov in POINT_OF_VIEWS {
+            for tree_line in state.iter(pov) {
+                let mut visible_line: Option<u8> = None;
+                for tree in tree_line {
+                    if match visible_line {
+                        None => true,
+                        Some(visible_line) => tree.height > visible_line
+                    } {
+                        visible_trees.insert(tree.coords);
+                        visible_line = Some(tree.height)
+                    }
                 }
             }
         }
+        Ok(visible_trees.len().into())
     }
-    println!("{}", visible_trees.len());
-
-    // part 2
-    let mut max_visibility = 0;
-    for row in 0..state.rows {
-        for column in 0..state.columns {
-            let tree_height = state.state[row as usize][column as usize];
-            let mut visibilities = Vec::<i32>::new();
-            for pov in POINT_OF_VIEWS {
-                let mut visibility = 0;
-                for tree in state.los_iter_from_tree(TreeCoordinates { row, column }, pov) {
-                    visibility += 1;
-                    if tree.height >= tree_height {
-                        break;
+
+    fn part2(&self, state: &State) -> Result<Answer, Error> {
+        let mut max_visibility = 0;
+        for row in 0..state.rows {
+            for column in 0..state.columns {
+                let tree_height = state.state[row as usize][column as usize];
+                let mut visibilities = Vec::<i32>::new();
+                for pov in POINT_OF_VIEWS {
+                    let mut visibility = 0;
+                    for tree in state.los_iter_from_tree(TreeCoordinates { row, column }, pov) {
+                        visibility += 1;
+                        if tree.height >= tree_height {
+                            break;
+                        }
                     }
+                    visibilities.push(visibility);
+                }
+                let visibility_product = visibilities.iter().product();
+                if max_visibility < visibility_product {
+                    max_visibility = visibility_product
                 }
-                visibilities.push(visibility);
-            }
-            let visibility_product = visibilities.iter().product();
-            if max_visibility < visibility_product {
-                max_visibility = visibility_product
             }
         }
+        Ok(max_visibility.into())
     }
-    println!("{}", max_visibility);
-
-    Ok(())
 }
 
 fn parse_line(line: &[u8], state: &mut State) {
@@ -238,4 +242,25 @@ fn parse_line(line: &[u8], state: &mut State) {
         state.rows = row.len() as u64;
     }
     state.state.push(row);
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 21.into());
+        assert_eq!(Day.part2(&input).unwrap(), 8.into());
+    }
+}
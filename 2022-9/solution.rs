@@ -1,6 +1,7 @@
 use std::collections::{HashSet};
-use std::{io, iter};
+use std::iter;
 use anyhow::{Context, Error};
+use lib::solution::{Answer, Solution};
 
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
 struct Coord {
@@ -19,7 +20,7 @@ struct RopePart {
     coord: Coord,
 }
 
-struct ParsedLine {
+pub struct ParsedLine {
     offset: Offset,
     amount: i32,
 }
@@ -73,21 +74,29 @@ impl RopePart {
     }
 }
 
-fn main() -> Result<(), Error> {
-    // part 1
-    // let mut rope = Rope::new(2);
-    // part 2
-    let mut rope = Rope::new(10);
+#[derive(Default)]
+pub struct Day;
 
-    let mut tail_visited = HashSet::<Coord>::new();
-    tail_visited.insert(rope.parts.last().unwrap().coord);
+impl Solution for Day {
+    type Input = Vec<ParsedLine>;
 
-    let stdin = io::stdin();
-    for line in stdin.lines() {
-        let line = line?;
+    fn parse(&self, input: &str) -> Result<Vec<ParsedLine>, Error> {
+        input.lines().map(parse_line).collect()
+    }
 
-        let parsed_line = parse_line(&line)?;
+    fn part1(&self, moves: &Vec<ParsedLine>) -> Result<Answer, Error> {
+        Ok(tail_visited(Rope::new(2), moves).into())
+    }
 
+    fn part2(&self, moves: &Vec<ParsedLine>) -> Result<Answer, Error> {
+        Ok(tail_visited(Rope::new(10), moves).into())
+    }
+}
+
+fn tail_visited(mut rope: Rope, moves: &[ParsedLine]) -> usize {
+    let mut tail_visited = HashSet::<Coord>::new();
+    tail_visited.insert(rope.parts.last().unwrap().coord);
+    for parsed_line in moves {
         for _ in 0..parsed_line.amount {
             rope.move_head_by(parsed_line.offset);
 
@@ -95,9 +104,7 @@ fn main() -> Result<(), Error> {
         }
     }
 
-    println!("{}", tail_visited.len());
-
-    Ok(())
+    tail_visited.len()
 }
 
 fn parse_line(line: &str) -> Result<ParsedLine, Error> {
@@ -126,4 +133,45 @@ fn parse_line(line: &str) -> Result<ParsedLine, Error> {
         },
         amount: amount_str.parse::<i32>().context("parasing move amount")?,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 13.into());
+        assert_eq!(Day.part2(&input).unwrap(), 1.into());
+    }
+
+    #[test]
+    fn test_larger_example_part2() {
+        let input = Day.parse(LARGER_EXAMPLE).unwrap();
+        assert_eq!(Day.part2(&input).unwrap(), 36.into());
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::{Context, Error};
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &String) -> Result<Answer, Error> {
+        Ok(calibration_sum(input, false)?.into())
+    }
+
+    fn part2(&self, input: &String) -> Result<Answer, Error> {
+        Ok(calibration_sum(input, true)?.into())
+    }
+}
+
+fn calibration_sum(input: &str, spelled_out: bool) -> Result<i64, Error> {
+    let mut sum = 0;
+    for line in input.lines() {
+        let mut digits: Option<Vec<char>> = None;
+
+        for (i, b) in line.bytes().enumerate() {
+            let digit: Option<char> = if b.is_ascii_digit() {
+                Some(b.into())
+            } else if spelled_out {
+                parse_buf(&line.as_bytes()[0..=i])
+            } else {
+                None
+            };
+            if let Some(digit) = digit {
+                if digits.is_none() {
+                    digits = Some(std::iter::repeat_n(digit, 2).collect());
+                }
+                digits.as_mut().unwrap()[1] = digit;
+            }
+        }
+        let num = String::from_iter(digits.as_ref().context("no digit in line")?).parse::<i64>()?;
+        sum += num;
+
+    }
+
+    Ok(sum)
+}
+
+const NUMBERS_STR: [&[u8]; 9] = [b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine"];
+
+fn parse_buf(buf: &[u8]) -> Option<char>{
+    for (i, number_str) in NUMBERS_STR.iter().enumerate() {
+        if buf.len() < number_str.len() {
+            continue
+        }
+        let substr = &buf[buf.len()-number_str.len()..];
+        if substr == *number_str {
+            return char::from_digit((i+1) as u32, 10)
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE_SPELLED_OUT: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn test_example_part1() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 142.into());
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = Day.parse(EXAMPLE_SPELLED_OUT).unwrap();
+        assert_eq!(Day.part2(&input).unwrap(), 281.into());
+    }
+}
@@ -1,9 +1,9 @@
 use std::collections::HashSet;
-use std::io;
 use std::ops::{Index, Neg};
 use anyhow::{Result, Ok, bail, Context};
+use lib::solution::{Answer, Solution};
 
-struct State {
+pub struct State {
     pipes: Vec<Vec<Pipe>>,
     start: Coord,
 }
@@ -68,15 +68,67 @@ struct Rotation {
 static ROTATE_RIGHT: Rotation = Rotation { x: -1, y: 1 };
 static ROTATE_LEFT: Rotation = Rotation { x: 1, y: -1 };
 
-fn main() -> Result<()> {
-    let state = parse()?;
+#[derive(Default)]
+pub struct Day;
 
+impl Solution for Day {
+    type Input = State;
+
+    fn parse(&self, input: &str) -> Result<State> {
+        parse(input)
+    }
+
+    fn part1(&self, state: &State) -> Result<Answer> {
+        let (path, _) = find_loop(state)?;
+        Ok((path.len() / 2).into())
+    }
+
+    fn part2(&self, state: &State) -> Result<Answer> {
+        let (path, visited) = find_loop(state)?;
+
+        let rightness: i64 = path.iter().map(|x| x.right_turn as i64).sum();
+        let rotate_to_inside = if rightness < 0 {
+            ROTATE_LEFT
+        } else if rightness > 0 {
+            ROTATE_RIGHT
+        } else {
+            bail!("unexpectedly straight loop");
+        };
+
+        let mut inner_coords: HashSet<Coord> = HashSet::new();
+        for step in &path {
+            let to_inside_offset = rotate(step.by, rotate_to_inside);
+            // due to turns, check each step in path twice:
+            // for "direction by which I got there" (from_coord = step.to)
+            // and "direction I got from there" (from_coord = previous step coord, with current step.by)
+            for from_coord in [step.to.offset(-step.by), step.to] {
+                let mut checked_coord = from_coord;
+                loop {
+                    checked_coord = checked_coord.offset(to_inside_offset);
+                    match visited.get(&checked_coord) {
+                        None => {}
+                        Some(_) => break
+                    }
+                    if checked_coord.x < 0 || checked_coord.y < 0
+                        || checked_coord.x >= state.pipes[0].len() as i64 || checked_coord.y >= state.pipes.len() as i64 {
+                        break;
+                    }
+                    inner_coords.insert(checked_coord);
+                }
+            }
+        }
+
+        Ok(inner_coords.len().into())
+    }
+}
+
+fn find_loop(state: &State) -> Result<(Vec<Step>, HashSet<Coord>)> {
     let mut path: Vec<Step> = Vec::new();
     let mut visited: HashSet<Coord> = HashSet::new();
     let mut current_coord = state.start;
     let mut last_move: Option<Offset> = None;
     loop {
-        let next = pipe_move(&state, current_coord, last_move)
+        let next = pipe_move(state, current_coord, last_move)
             .with_context(|| format!("at: {:?}, from: {:?}", current_coord, last_move))?;
         current_coord = next.to;
         last_move = Some(next.by);
@@ -87,43 +139,7 @@ fn main() -> Result<()> {
         }
     }
 
-    let rightness: i64 = path.iter().map(|x| x.right_turn as i64).sum();
-    let rotate_to_inside = if rightness < 0 {
-        ROTATE_LEFT
-    } else if rightness > 0 {
-        ROTATE_RIGHT
-    } else {
-        bail!("unexpectedly straight loop");
-    };
-
-    let mut inner_coords: HashSet<Coord> = HashSet::new();
-    for step in &path {
-        let to_inside_offset = rotate(step.by, rotate_to_inside);
-        // due to turns, check each step in path twice:
-        // for "direction by which I got there" (from_coord = step.to)
-        // and "direction I got from there" (from_coord = previous step coord, with current step.by)
-        for from_coord in [step.to.offset(-step.by), step.to] {
-            let mut checked_coord = from_coord;
-            loop {
-                checked_coord = checked_coord.offset(to_inside_offset);
-                match visited.get(&checked_coord) {
-                    None => {}
-                    Some(_) => break
-                }
-                if checked_coord.x < 0 || checked_coord.y < 0
-                    || checked_coord.x >= state.pipes[0].len() as i64 || checked_coord.y >= state.pipes.len() as i64 {
-                    break;
-                }
-                inner_coords.insert(checked_coord);
-            }
-        }
-    }
-
-    println!("{}", path.len() / 2);
-
-    println!("{}", inner_coords.len());
-
-    Ok(())
+    Ok((path, visited))
 }
 
 fn pipe_move(state: &State, current_coord: Coord, last_move: Option<Offset>) -> Result<Step> {
@@ -148,15 +164,12 @@ fn pipe_move(state: &State, current_coord: Coord, last_move: Option<Offset>) ->
                     None
                 } else {
                     state.pipes.get(potential_next_coord.y as usize)
-                        .map(|row| row.get(potential_next_coord.x as usize))
-                        .flatten()
+                        .and_then(|row| row.get(potential_next_coord.x as usize))
                 };
-                match potential_pipe {
-                    None => {} // this pipe would be out of bounds
-                    Some(pipe) => {
-                        if is_connected_from(pipe, offset) {
-                            return Ok(Step { to: potential_next_coord, by: offset, right_turn: 0 });
-                        }
+                // None if this pipe would be out of bounds
+                if let Some(pipe) = potential_pipe {
+                    if is_connected_from(pipe, offset) {
+                        return Ok(Step { to: potential_next_coord, by: offset, right_turn: 0 });
                     }
                 }
             }
@@ -198,12 +211,10 @@ fn next_offset(offsets: &[Offset; 2], offset: Offset) -> Option<Offset> {
 }
 
 
-fn parse() -> Result<State> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<State> {
     let mut result: Vec<Vec<Pipe>> = Vec::new();
     let mut start: Option<Coord> = None;
-    for (y, line) in stdin.lines().enumerate() {
-        let line = line?;
+    for (y, line) in input.lines().enumerate() {
         let mut line_vec: Vec<Pipe> = Vec::new();
         for (x, c) in line.chars().enumerate() {
             let pipe = match c {
@@ -237,7 +248,8 @@ fn parse() -> Result<State> {
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
-    use crate::{cross_product, rotate, Offset, Rotation, UP, LEFT, RIGHT, ROTATE_LEFT, ROTATE_RIGHT};
+    use super::{Day, LEFT, Offset, RIGHT, ROTATE_LEFT, ROTATE_RIGHT, Rotation, UP, cross_product, rotate};
+    use lib::solution::Solution;
 
     #[rstest]
     #[case(UP, LEFT, - 1)]
@@ -253,4 +265,75 @@ mod tests {
     fn test_rotate(#[case] a: Offset, #[case] rotation: Rotation, #[case] expected_offset: Offset) {
         assert_eq!(rotate(a, rotation), expected_offset)
     }
-}
\ No newline at end of file
+
+    const EXAMPLE_SQUARE: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+
+    const EXAMPLE_COMPLEX: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    const EXAMPLE_ENCLOSED: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    const EXAMPLE_LARGER: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+    const EXAMPLE_JUNK: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[rstest]
+    #[case(EXAMPLE_SQUARE, 4)]
+    #[case(EXAMPLE_COMPLEX, 8)]
+    fn test_example_part1(#[case] example: &str, #[case] expected: u64) {
+        let input = Day.parse(example).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), expected.into());
+    }
+
+    #[rstest]
+    #[case(EXAMPLE_ENCLOSED, 4)]
+    #[case(EXAMPLE_LARGER, 8)]
+    #[case(EXAMPLE_JUNK, 10)]
+    fn test_example_part2(#[case] example: &str, #[case] expected: u64) {
+        let input = Day.parse(example).unwrap();
+        assert_eq!(Day.part2(&input).unwrap(), expected.into());
+    }
+}
@@ -0,0 +1,139 @@
+use anyhow::{Result, Ok};
+use lib::grid::{Compression, Coord, Grid};
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
+
+pub struct Space {
+    galaxies: Vec<Coord>,
+    // every galaxy row and column on its own, the empty ones between them merged
+    compression: Compression,
+    galaxy_cells: Grid<bool>,
+}
+
+impl Space {
+    // expanded coordinates of the galaxies, by the cells before them with empty ones grown
+    fn expanded(&self, expansion: u64) -> Vec<(u64, u64)> {
+        let (columns, rows) = (self.compression.columns(), self.compression.rows());
+        let column_empty = |x: usize| (0..rows).all(|y| !self.galaxy_cells.index(x, y));
+        let row_empty = |y: usize| (0..columns).all(|x| !self.galaxy_cells.index(x, y));
+        let xs = starts(columns, |x| self.compression.width(x) as u64, column_empty, expansion);
+        let ys = starts(rows, |y| self.compression.height(y) as u64, row_empty, expansion);
+        self.galaxies
+            .iter()
+            .map(|&galaxy| {
+                let cell = self.compression.cell(galaxy).expect("galaxies are compressed");
+                (xs[cell.x as usize], ys[cell.y as usize])
+            })
+            .collect()
+    }
+}
+
+fn starts(cells: usize, size: impl Fn(usize) -> u64, empty: impl Fn(usize) -> bool, expansion: u64) -> Vec<u64> {
+    let mut starts = vec![0];
+    for i in 0..cells {
+        let size = if empty(i) { size(i) * expansion } else { size(i) };
+        starts.push(starts[i] + size);
+    }
+    starts
+}
+
+pub struct Day {
+    // how many times larger empty rows and columns become in part 2
+    expansion: u64,
+}
+
+impl Default for Day {
+    fn default() -> Self {
+        Day { expansion: 1_000_000 }
+    }
+}
+
+impl Solution for Day {
+    type Input = Space;
+
+    fn parse(&self, input: &str) -> Result<Space> {
+        parse(input)
+    }
+
+    fn part1(&self, space: &Space) -> Result<Answer> {
+        Ok(distances_sum(space, 2).into())
+    }
+
+    fn part2(&self, space: &Space) -> Result<Answer> {
+        Ok(distances_sum(space, self.expansion).into())
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new().with("expansion", &mut self.expansion)
+    }
+}
+
+fn distances_sum(space: &Space, expansion: u64) -> u64 {
+    let galaxies = space.expanded(expansion);
+    let mut sum: u64 = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            sum += galaxies[i].0.abs_diff(galaxies[j].0) + galaxies[i].1.abs_diff(galaxies[j].1);
+        }
+    }
+    sum
+}
+
+fn parse(input: &str) -> Result<Space> {
+    let mut galaxies = Vec::<Coord>::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c == '#' {
+                galaxies.push(Coord {
+                    x: x as i64,
+                    y: y as i64,
+                })
+            };
+        }
+    }
+
+    let compression = Compression::new(galaxies.iter().copied());
+    let mut galaxy_cells = compression.grid(false);
+    for &galaxy in &galaxies {
+        galaxy_cells[compression.cell(galaxy).expect("galaxies are compressed")] = true;
+    }
+    Ok(Space {
+        galaxies,
+        compression,
+        galaxy_cells,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn test_example_part1() {
+        let input = Day::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day::default().part1(&input).unwrap(), 374.into());
+    }
+
+    #[rstest]
+    #[case(10, 1030)]
+    #[case(100, 8410)]
+    fn test_example_part2(#[case] expansion: u64, #[case] expected: u64) {
+        let day = Day { expansion };
+        assert_eq!(day.part2(&day.parse(EXAMPLE).unwrap()).unwrap(), expected.into());
+    }
+}
@@ -1,9 +1,9 @@
 use std::cmp::min;
 use std::collections::HashMap;
-use std::io;
 use anyhow::{Result, Ok, Context, bail};
+use lib::solution::{Answer, Solution};
 
-struct SpringLine {
+pub struct SpringLine {
     springs: Vec<Spring>,
     groups: Vec<usize>,
 }
@@ -118,20 +118,23 @@ enum Spring {
     Operational,
 }
 
-fn main() -> Result<()> {
-    let stdin = io::stdin();
-    let mut sum = 0;
-    for line in stdin.lines() {
-        let line = line?;
-        let spring_line = parse_line(&line)?;
-        let spring_line = spring_line.unfold(5);
-        let count = spring_line.arrangement_count();
-        sum += count;
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<SpringLine>;
+
+    fn parse(&self, input: &str) -> Result<Vec<SpringLine>> {
+        input.lines().map(parse_line).collect()
     }
 
-    println!("{}", sum);
+    fn part1(&self, spring_lines: &Vec<SpringLine>) -> Result<Answer> {
+        Ok(spring_lines.iter().map(|spring_line| spring_line.arrangement_count()).sum::<u64>().into())
+    }
 
-    Ok(())
+    fn part2(&self, spring_lines: &Vec<SpringLine>) -> Result<Answer> {
+        Ok(spring_lines.iter().map(|spring_line| spring_line.unfold(5).arrangement_count()).sum::<u64>().into())
+    }
 }
 
 fn parse_line(line: &str) -> Result<SpringLine> {
@@ -147,3 +150,24 @@ fn parse_line(line: &str) -> Result<SpringLine> {
     })
 }
 
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 21.into());
+        assert_eq!(Day.part2(&input).unwrap(), 525152.into());
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::{Result, Ok, bail};
+use lib::grid::Grid;
+use lib::solution::{Answer, Solution};
+
+pub type Map = Grid<Point>;
+
+#[derive(PartialEq, Clone)]
+pub enum Point {
+    Ash,
+    Rock,
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Map>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Map>> {
+        parse(input)
+    }
+
+    fn part1(&self, maps: &Vec<Map>) -> Result<Answer> {
+        Ok(summarize(maps, 0).into())
+    }
+
+    fn part2(&self, maps: &Vec<Map>) -> Result<Answer> {
+        Ok(summarize(maps, 1).into())
+    }
+}
+
+fn summarize(maps: &[Map], smudge_count: usize) -> usize {
+    let mut sum = 0;
+
+    for map in maps {
+        // vertical reflection lines are the horizontal ones of the transposed map
+        sum += reflection_rows(&map.transpose(), smudge_count).sum::<usize>();
+        sum += 100 * reflection_rows(map, smudge_count).sum::<usize>();
+    }
+
+    sum
+}
+
+// rows above which the map is mirrored, with exactly smudge_count points differing from their reflection
+fn reflection_rows(map: &Map, smudge_count: usize) -> impl Iterator<Item = usize> + '_ {
+    (1..map.rows()).filter(move |&row| {
+        let smudges: usize = (0..row)
+            .rev()
+            .zip(row..map.rows())
+            .map(|(above, below)| {
+                map.row(above)
+                    .iter()
+                    .zip(map.row(below))
+                    .filter(|(a, b)| a != b)
+                    .count()
+            })
+            .sum();
+        smudges == smudge_count
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Map>> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            Ok(Grid::from_lines_try_iter_map(
+                block.lines().map(|line| -> Result<_> { Ok(line.chars()) }),
+                |_, c| -> Result<_> {
+                    Ok(match c {
+                        '#' => Point::Rock,
+                        '.' => Point::Ash,
+                        _ => bail!("invalid point"),
+                    })
+                },
+            )?)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::{reflection_rows, Day};
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 405.into());
+        assert_eq!(Day.part2(&input).unwrap(), 400.into());
+    }
+
+    #[rstest]
+    #[case(0, 0, 5)]
+    #[case(1, 4, 0)]
+    fn test_reflection_rows(#[case] map: usize, #[case] row: usize, #[case] column: usize) {
+        let maps = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(reflection_rows(&maps[map], 0).sum::<usize>(), row);
+        assert_eq!(reflection_rows(&maps[map].transpose(), 0).sum::<usize>(), column);
+    }
+}
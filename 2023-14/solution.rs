@@ -0,0 +1,115 @@
+use anyhow::{Result, Ok, bail};
+use lib::cycle;
+use lib::grid::{Coord, Grid};
+use lib::solution::{Answer, Solution};
+
+pub type Map = Grid<Point>;
+
+#[derive(PartialEq, Copy, Clone, Hash, Eq)]
+pub enum Point {
+    Empty,
+    RoundRock,
+    CubeRock,
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Map> {
+        parse(input)
+    }
+
+    fn part1(&self, map: &Map) -> Result<Answer> {
+        let mut map = map.clone();
+        roll_north(&mut map);
+        Ok(rocks_load(&map).into())
+    }
+
+    fn part2(&self, map: &Map) -> Result<Answer> {
+        let map = cycle::nth(map.clone(), |map| spin_cycle(map.clone()), 1_000_000_000);
+        Ok(rocks_load(&map).into())
+    }
+}
+
+fn rocks_load(map: &Map) -> usize {
+    map.iter()
+        .filter(|(_, &point)| point == Point::RoundRock)
+        .map(|(coord, _)| map.rows() - coord.y as usize)
+        .sum()
+}
+
+fn roll_north(map: &mut Map) {
+    for x in 0..map.columns() {
+        // row where the next round rock of the column stops
+        let mut free = 0;
+        for y in 0..map.rows() {
+            match *map.index(x, y) {
+                Point::Empty => {}
+                Point::CubeRock => free = y + 1,
+                Point::RoundRock => {
+                    map.swap(coord(x, free), coord(x, y));
+                    free += 1;
+                }
+            }
+        }
+    }
+}
+
+// rolls north, west, south and east, turning the map so that each of them is north in turn
+fn spin_cycle(mut map: Map) -> Map {
+    for _ in 0..4 {
+        roll_north(&mut map);
+        map = map.rotate_cw();
+    }
+    map
+}
+
+fn coord(x: usize, y: usize) -> Coord {
+    Coord{
+        x: x as i64,
+        y: y as i64,
+    }
+}
+
+fn parse(input: &str) -> Result<Map> {
+    Ok(Grid::from_lines_try_iter_map(
+        input.lines().map(|line| -> Result<_> { Ok(line.chars()) }),
+        |_, c| -> Result<_> {
+            Ok(match c {
+                '.' => Point::Empty,
+                'O' => Point::RoundRock,
+                '#' => Point::CubeRock,
+                _ => bail!("invalid point"),
+            })
+        },
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 136.into());
+        assert_eq!(Day.part2(&input).unwrap(), 64.into());
+    }
+}
@@ -1,5 +1,5 @@
-use std::io;
 use anyhow::{Result, Ok, Context};
+use lib::solution::{Answer, Solution};
 
 struct LensBoxes {
     boxes: Vec<LensBox>,
@@ -75,35 +75,62 @@ fn hash_bytes(bytes: &[u8]) -> u8 {
     current_value
 }
 
-fn main() -> Result<()> {
-    let stdin = io::stdin();
-    let line = stdin.lines().next().context("line missing")??;
-    let splits = line.split(",");
-    let mut lense_boxes: LensBoxes = Default::default();
-    let mut sum: u64 = 0;
-    for split in splits {
-        let pos = split.find(&['-', '=']).context("operation separator missing")?;
-        let label = &split[0..pos];
-        let operation = split.as_bytes()[pos];
-        let box_i = hash_bytes(label.as_bytes());
-        match operation {
-            b'-' => {
-                lense_boxes.remove_lens(box_i, label)
-            }
-            b'=' => {
-                let focal_length = split.get(pos + 1..).context("missing focal length")?;
-                let focal_length = focal_length.parse::<u8>()?;
-                lense_boxes.add_lens(box_i, label, focal_length)
-            }
-            _ => unreachable!("only position of - or = could be found")
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        let line = input.lines().next().context("line missing")?;
+        Ok(line.split(",").map(str::to_string).collect())
+    }
+
+    fn part1(&self, steps: &Vec<String>) -> Result<Answer> {
+        let mut sum: u64 = 0;
+        for step in steps {
+            let hash = hash_bytes(step.as_bytes());
+            sum += hash as u64;
         }
+        Ok(sum.into())
+    }
 
-        let hash = hash_bytes(split.as_bytes());
-        sum += hash as u64;
+    fn part2(&self, steps: &Vec<String>) -> Result<Answer> {
+        let mut lense_boxes: LensBoxes = Default::default();
+        for step in steps {
+            let pos = step.find(['-', '=']).context("operation separator missing")?;
+            let label = &step[0..pos];
+            let operation = step.as_bytes()[pos];
+            let box_i = hash_bytes(label.as_bytes());
+            match operation {
+                b'-' => {
+                    lense_boxes.remove_lens(box_i, label)
+                }
+                b'=' => {
+                    let focal_length = step.get(pos + 1..).context("missing focal length")?;
+                    let focal_length = focal_length.parse::<u8>()?;
+                    lense_boxes.add_lens(box_i, label, focal_length)
+                }
+                _ => unreachable!("only position of - or = could be found")
+            }
+        }
+        Ok(lense_boxes.focusing_power().into())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
 
-    println!("{}", sum);
-    println!("{}", lense_boxes.focusing_power());
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
 
-    Ok(())
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 1320.into());
+        assert_eq!(Day.part2(&input).unwrap(), 145.into());
+    }
 }
@@ -1,11 +1,11 @@
 use std::collections::HashSet;
-use std::io;
 use std::ops::{Index, Neg};
 use anyhow::{Result, Ok, Context};
+use lib::solution::{Answer, Solution};
 use thiserror::Error;
 
 #[derive(Clone)]
-struct Map {
+pub struct Map {
     points: Vec<Point>,
     rows: usize,
     columns: usize,
@@ -25,8 +25,8 @@ impl Map {
 
         let mut input_rays = vec![from];
 
-        while !input_rays.is_empty() {
-            let input_ray = input_rays.pop().unwrap();
+        while let Some(input_ray) = input_rays.pop() {
+            
             let current_point = self.index(input_ray.coord.x as usize, input_ray.coord.y as usize);
             let output_directions = current_point.to_output_directions(input_ray.direction);
             for output_direction in output_directions {
@@ -103,8 +103,7 @@ impl Point {
             }],
             Point::Splitter(split_directions) => split_directions
                 .iter()
-                .filter(|x| **x != -input_direction)
-                .map(|x| *x)
+                .filter(|x| **x != -input_direction).copied()
                 .collect()
         }
     }
@@ -139,46 +138,56 @@ static LEFT: Offset = Offset { x: -1, y: 0 };
 static DOWN: Offset = Offset { x: 0, y: 1 };
 static RIGHT: Offset = Offset { x: 1, y: 0 };
 
-fn main() -> Result<()> {
-    let map = parse()?;
+#[derive(Default)]
+pub struct Day;
 
-    let mut max = 0;
+impl Solution for Day {
+    type Input = Map;
 
-    for from_column in 0..map.columns {
-        for from_direction in [DOWN, UP] {
-            let from = LightTrace {
-                coord: Coord { x: from_column as i64, y: if from_direction == DOWN { 0 } else { map.rows as i64 - 1 } },
-                direction: from_direction,
-            };
-            let light_traces = map.trace(from);
-            let unique_tiles = light_traces.iter().map(|x| x.coord).collect::<HashSet<_>>().len();
-            max = std::cmp::max(max, unique_tiles)
-        }
+    fn parse(&self, input: &str) -> Result<Map> {
+        parse(input)
     }
-    for from_row in 0..map.rows {
-        for from_direction in [RIGHT, LEFT] {
-            let from = LightTrace {
-                coord: Coord { x: if from_direction == RIGHT { 0 } else { map.columns as i64 - 1 }, y: from_row as i64 },
-                direction: from_direction,
-            };
-            let light_traces = map.trace(from);
-            let unique_tiles = light_traces.iter().map(|x| x.coord).collect::<HashSet<_>>().len();
-            max = std::cmp::max(max, unique_tiles)
-        }
+
+    fn part1(&self, map: &Map) -> Result<Answer> {
+        Ok(energized(map, LightTrace { coord: Coord { x: 0, y: 0 }, direction: RIGHT }).into())
     }
 
-    println!("{}", max);
+    fn part2(&self, map: &Map) -> Result<Answer> {
+        let mut max = 0;
+
+        for from_column in 0..map.columns {
+            for from_direction in [DOWN, UP] {
+                let from = LightTrace {
+                    coord: Coord { x: from_column as i64, y: if from_direction == DOWN { 0 } else { map.rows as i64 - 1 } },
+                    direction: from_direction,
+                };
+                max = std::cmp::max(max, energized(map, from))
+            }
+        }
+        for from_row in 0..map.rows {
+            for from_direction in [RIGHT, LEFT] {
+                let from = LightTrace {
+                    coord: Coord { x: if from_direction == RIGHT { 0 } else { map.columns as i64 - 1 }, y: from_row as i64 },
+                    direction: from_direction,
+                };
+                max = std::cmp::max(max, energized(map, from))
+            }
+        }
+
+        Ok(max.into())
+    }
+}
 
-    Ok(())
+fn energized(map: &Map, from: LightTrace) -> usize {
+    let light_traces = map.trace(from);
+    light_traces.iter().map(|x| x.coord).collect::<HashSet<_>>().len()
 }
 
-fn parse() -> Result<Map> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<Map> {
     let mut points: Vec<Point> = Vec::new();
     let mut rows = 0;
     let mut columns = 0;
-    for line in stdin.lines() {
-        let line = line?;
+    for line in input.lines() {
         columns = line.chars().count();
         rows += 1;
 
@@ -197,7 +206,8 @@ fn parse() -> Result<Map> {
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
-    use crate::{UP, LEFT, RIGHT, DOWN, Point, Offset};
+    use super::{DOWN, Day, LEFT, Offset, Point, RIGHT, UP};
+    use lib::solution::Solution;
 
     #[rstest]
     #[case(RIGHT, '.', vec ! [RIGHT])]
@@ -210,4 +220,24 @@ mod tests {
         let point: Point = c.try_into().unwrap();
         assert_eq!(point.to_output_directions(input), expected_output)
     }
-}
\ No newline at end of file
+
+    const EXAMPLE: &str = "\
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 46.into());
+        assert_eq!(Day.part2(&input).unwrap(), 51.into());
+    }
+}
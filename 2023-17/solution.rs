@@ -0,0 +1,149 @@
+use anyhow::{Result, Context};
+use lib::grid::{Coord, Grid, Offset, OFFSET_RIGHT};
+use lib::search::astar;
+use lib::solution::{Answer, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct PathState {
+    coord: Coord,
+    last_move: Offset,
+    straight_moves: Option<u8>,
+}
+
+fn shortest_path(map: &Grid<u64>, from: Coord, to: Coord, min_to_turn: u8, max_to_straight: u8) -> Option<u64> {
+    let can_turn = |state: &PathState| match state.straight_moves {
+        None => true,
+        Some(moves) => moves >= min_to_turn
+    };
+
+    let path = astar(
+        [PathState {
+            coord: from,
+            last_move: OFFSET_RIGHT,
+            straight_moves: None,
+        }],
+        |state| {
+            let mut directions: Vec<Offset> = Vec::new();
+
+            if can_turn(state) {
+                directions.push(state.last_move.rotate_left());
+                directions.push(state.last_move.rotate_right());
+            }
+
+            // can go straight?
+            if match state.straight_moves {
+                None => true,
+                Some(moves) => moves < max_to_straight
+            } {
+                directions.push(state.last_move);
+            }
+
+            directions
+                .into_iter()
+                .map(|direction| (direction, state.coord + direction))
+                .filter(|&(_, new_coord)| map.is_valid(new_coord))
+                .map(|(direction, new_coord)| {
+                    let new_state = PathState {
+                        coord: new_coord,
+                        last_move: direction,
+                        straight_moves: Some(
+                            if direction == state.last_move {
+                                state.straight_moves.unwrap_or(0) + 1
+                            } else {
+                                1
+                            }
+                        ),
+                    };
+                    (new_state, map[new_coord])
+                })
+                .collect::<Vec<_>>()
+        },
+        |state| state.coord.x.abs_diff(to.x) + state.coord.y.abs_diff(to.y),
+        |state| state.coord == to && can_turn(state),
+    )?;
+
+    Some(path.cost)
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<u64>;
+
+    fn parse(&self, input: &str) -> Result<Grid<u64>> {
+        parse(input)
+    }
+
+    fn part1(&self, map: &Grid<u64>) -> Result<Answer> {
+        Ok(least_heat_loss(map, 1, 3)?.into())
+    }
+
+    fn part2(&self, map: &Grid<u64>) -> Result<Answer> {
+        Ok(least_heat_loss(map, 4, 10)?.into())
+    }
+}
+
+fn least_heat_loss(map: &Grid<u64>, min_to_turn: u8, max_to_straight: u8) -> Result<u64> {
+    shortest_path(
+        map,
+        Coord { x: 0, y: 0 },
+        Coord { x: map.columns() as i64 - 1, y: map.rows() as i64 - 1 },
+        min_to_turn,
+        max_to_straight,
+    ).context("no path found")
+}
+
+fn parse(input: &str) -> Result<Grid<u64>> {
+    Ok(Grid::from_lines_try_iter_map(
+        input.lines().map(|line| -> Result<_> {
+            Ok(line.chars().collect::<Vec<_>>())
+        }),
+        |_, c| -> Result<u64> {
+            Ok(c.to_digit(10).context("invalid char")? as u64)
+        },
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    const EXAMPLE_UNFORTUNATE: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 102.into());
+        assert_eq!(Day.part2(&input).unwrap(), 94.into());
+    }
+
+    #[test]
+    fn test_unfortunate_example_part2() {
+        let input = Day.parse(EXAMPLE_UNFORTUNATE).unwrap();
+        assert_eq!(Day.part2(&input).unwrap(), 71.into());
+    }
+}
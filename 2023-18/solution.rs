@@ -0,0 +1,247 @@
+use anyhow::{Result, Ok, bail, Context};
+use lib::geometry::Polygon;
+use lib::grid::{Offset, OFFSET_DOWN, OFFSET_LEFT, OFFSET_RIGHT, OFFSET_UP};
+use lib::solution::{Answer, Solution};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = DigPlan;
+
+    fn parse(&self, input: &str) -> Result<DigPlan> {
+        parse(input)
+    }
+
+    fn part1(&self, plan: &DigPlan) -> Result<Answer> {
+        Ok(lagoon_area(&plan.instructions)?.into())
+    }
+
+    fn part2(&self, plan: &DigPlan) -> Result<Answer> {
+        Ok(lagoon_area(&plan.hex_instructions)?.into())
+    }
+}
+
+fn lagoon_area(instructions: &[Input]) -> Result<i64> {
+    // the trench is dug through whole cells, the lagoon being every cell inside or on the walk
+    lagoon_polygon(instructions).lattice_points().context("lagoon area overflows")
+}
+
+fn lagoon_polygon(instructions: &[Input]) -> Polygon {
+    Polygon::from_walk(instructions.iter().map(|instruction| (instruction.direction, instruction.count)))
+}
+
+struct Input {
+    direction: Offset,
+    count: i64,
+}
+
+// the same plan read as written (part 1) and from the hex colour codes (part 2)
+pub struct DigPlan {
+    instructions: Vec<Input>,
+    hex_instructions: Vec<Input>,
+}
+
+static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\w) (\w+) \(#(\w{6})\)").unwrap());
+
+fn parse(input: &str) -> Result<DigPlan> {
+    let mut plan = DigPlan {
+        instructions: Vec::new(),
+        hex_instructions: Vec::new(),
+    };
+    for line in input.lines() {
+        let captures = RE.captures(line).context("invalid line")?;
+        let (_, groups) = captures.extract::<3>();
+        plan.instructions.push(Input {
+            direction: match groups[0] {
+                "R" => OFFSET_RIGHT,
+                "D" => OFFSET_DOWN,
+                "L" => OFFSET_LEFT,
+                "U" => OFFSET_UP,
+                _ => bail!("invalid direction")
+            },
+            count: groups[1].parse()?,
+        });
+
+        let (hex_len, dir_digit) = groups[2].split_at(5);
+        plan.hex_instructions.push(Input {
+            direction: match dir_digit {
+                "0" => OFFSET_RIGHT,
+                "1" => OFFSET_DOWN,
+                "2" => OFFSET_LEFT,
+                "3" => OFFSET_UP,
+                _ => bail!("invalid direction")
+            },
+            count: i64::from_str_radix(hex_len, 16)?,
+        })
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day, Input, lagoon_polygon};
+    use lib::grid::{OFFSET_DOWN as DOWN, OFFSET_LEFT as LEFT, OFFSET_RIGHT as RIGHT, OFFSET_UP as UP};
+    use lib::solution::Solution;
+
+    // Maybe there is some edge case when start is / is not on border.
+
+    #[test]
+    fn test_l_shape_indexing() {
+        let mut input = vec![
+            Input {
+                direction: RIGHT,
+                count: 10,
+            },
+            Input {
+                direction: UP,
+                count: 10,
+            },
+            Input {
+                direction: RIGHT,
+                count: 10,
+            },
+            Input {
+                direction: DOWN,
+                count: 20,
+            },
+            Input {
+                direction: LEFT,
+                count: 20,
+            },
+            Input {
+                direction: UP,
+                count: 10,
+            },
+        ];
+        for i in 0..input.len() {
+            let area = lagoon_polygon(&input).double_signed_area().unwrap().abs() / 2;
+            assert_eq!(area, 300, "rotation {}", i); // 400 main - 100 inner indent
+
+            input.rotate_left(1);
+        }
+    }
+
+    #[test]
+    fn test_u_shape_indexing() {
+        let mut input = vec![
+            Input {
+                direction: RIGHT,
+                count: 10,
+            },
+            Input {
+                direction: UP,
+                count: 10,
+            },
+            Input {
+                direction: LEFT,
+                count: 10,
+            },
+            Input {
+                direction: UP,
+                count: 10,
+            },
+            Input {
+                direction: RIGHT,
+                count: 20,
+            },
+            Input {
+                direction: DOWN,
+                count: 30,
+            },
+            Input {
+                direction: LEFT,
+                count: 20,
+            },
+            Input {
+                direction: UP,
+                count: 10,
+            },
+        ];
+        for i in 0..input.len() {
+            let area = lagoon_polygon(&input).double_signed_area().unwrap().abs() / 2;
+            assert_eq!(area, 500, "rotation {}", i); // 600 main - 100 inner indent
+
+            input.rotate_left(1);
+        }
+    }
+
+    #[test]
+    fn test_l_in_u_shape_indexing() {
+        let mut input = vec![
+            Input {
+                direction: RIGHT,
+                count: 10,
+            },
+            Input {
+                direction: UP,
+                count: 5,
+            },
+            Input {
+                direction: LEFT,
+                count: 5,
+            },
+            Input {
+                direction: UP,
+                count: 5,
+            },
+            Input {
+                direction: LEFT,
+                count: 5,
+            },
+            Input {
+                direction: UP,
+                count: 10,
+            },
+            Input {
+                direction: RIGHT,
+                count: 20,
+            },
+            Input {
+                direction: DOWN,
+                count: 30,
+            },
+            Input {
+                direction: LEFT,
+                count: 20,
+            },
+            Input {
+                direction: UP,
+                count: 10,
+            },
+        ];
+        for i in 0..input.len() {
+            let area = lagoon_polygon(&input).double_signed_area().unwrap().abs() / 2;
+            assert_eq!(area, 525, "rotation {}", i); // 600 main - 100 inner indent + 25 inner most outdent
+
+            input.rotate_left(1);
+        }
+    }
+
+    const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 62.into());
+        assert_eq!(Day.part2(&input).unwrap(), 952408144115_u64.into());
+    }
+}
@@ -1,12 +1,12 @@
 use std::cmp::{max, min};
 use std::collections::HashMap;
-use std::io;
 use std::ops::RangeInclusive;
 use anyhow::{Result, Ok, Context, bail};
+use lib::solution::{Answer, Solution};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-struct Puzzle {
+pub struct Puzzle {
     workflows: HashMap<String, Workflow>,
     objects: Vec<Object>,
 }
@@ -75,47 +75,53 @@ enum ObjectAttribute {
     S,
 }
 
-fn main() -> Result<()> {
-    Lazy::force(&RE_OBJECT);
-    Lazy::force(&RE_RULE);
+#[derive(Default)]
+pub struct Day;
 
-    let puzzle = parse()?;
+impl Solution for Day {
+    type Input = Puzzle;
 
-    // part 1
-    let mut sum = 0;
-    for object in &puzzle.objects {
-        if matches!(process_object(&puzzle.workflows, object)?, ObjectResult::Accept) {
-            sum += object.attributes.values().sum::<i64>();
-        }
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse(input)
     }
-    println!("{sum}");
-
-    // part 2
-    let all_possibilities_multi_object = MultiObject {
-        attributes: [
-            ObjectAttribute::X,
-            ObjectAttribute::M,
-            ObjectAttribute::A,
-            ObjectAttribute::S,
-        ]
-            .into_iter()
-            .map(|k| (k, 1..=4000))
-            .collect(),
-    };
 
-    let multi_object_results = process_multi_object(&puzzle.workflows, all_possibilities_multi_object)?;
-    let mut sum = 0;
-    for multi_object_result in &multi_object_results {
-        if let ObjectResult::Accept = multi_object_result.result {
-            sum += multi_object_result.multi_object.attributes
-                .values()
-                .map(|x| x.clone().count() as i64)
-                .product::<i64>();
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let mut sum = 0;
+        for object in &puzzle.objects {
+            if matches!(process_object(&puzzle.workflows, object)?, ObjectResult::Accept) {
+                sum += object.attributes.values().sum::<i64>();
+            }
         }
+
+        Ok(sum.into())
     }
-    println!("{sum}");
 
-    Ok(())
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let all_possibilities_multi_object = MultiObject {
+            attributes: [
+                ObjectAttribute::X,
+                ObjectAttribute::M,
+                ObjectAttribute::A,
+                ObjectAttribute::S,
+            ]
+                .into_iter()
+                .map(|k| (k, 1..=4000))
+                .collect(),
+        };
+
+        let multi_object_results = process_multi_object(&puzzle.workflows, all_possibilities_multi_object)?;
+        let mut sum = 0;
+        for multi_object_result in &multi_object_results {
+            if let ObjectResult::Accept = multi_object_result.result {
+                sum += multi_object_result.multi_object.attributes
+                    .values()
+                    .map(|x| x.clone().count() as i64)
+                    .product::<i64>();
+            }
+        }
+
+        Ok(sum.into())
+    }
 }
 
 fn process_object(workflows: &HashMap<String, Workflow>, object: &Object) -> Result<ObjectResult> {
@@ -273,15 +279,13 @@ fn split_multi_object(
 static RE_OBJECT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)}").unwrap());
 static RE_RULE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\w)([<>])(\d+):(\w+)").unwrap());
 
-fn parse() -> Result<Puzzle> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<Puzzle> {
     let mut puzzle = Puzzle {
         workflows: Default::default(),
         objects: vec![],
     };
     let mut parsing_objects = false;
-    for line in stdin.lines() {
-        let line = line?;
+    for line in input.lines() {
         match parsing_objects {
             false => {
                 if line.is_empty() {
@@ -320,7 +324,7 @@ fn parse() -> Result<Puzzle> {
                 puzzle.workflows.insert(name.to_owned(), Workflow { rules });
             }
             true => {
-                let (_, captured) = RE_OBJECT.captures(line.as_str()).context("invalid object definition")?.extract::<4>();
+                let (_, captured) = RE_OBJECT.captures(line).context("invalid object definition")?.extract::<4>();
                 puzzle.objects.push(Object {
                     attributes: [
                         (ObjectAttribute::X, captured[0].parse::<i64>()?),
@@ -341,4 +345,37 @@ fn parse_rule_target(s: &str) -> RuleTarget {
         "R" => RuleTarget::Reject,
         s => RuleTarget::ToWorkflow(s.to_owned())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 19114.into());
+        assert_eq!(Day.part2(&input).unwrap(), 167409079868000_u64.into());
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use anyhow::{Context, Error};
+use lib::solution::{Answer, Solution};
+use once_cell::sync::Lazy;
+use regex::{Regex};
+
+static RE_ID: Lazy<Regex> = Lazy::new(|| Regex::new(r#"Game (\d+)"#).unwrap());
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<GameState>;
+
+    fn parse(&self, input: &str) -> Result<Vec<GameState>, Error> {
+        input.lines().map(parse_game_state).collect()
+    }
+
+    fn part1(&self, game_states: &Vec<GameState>) -> Result<Answer, Error> {
+        let limit_map = HashMap::from([
+            ("red", 12),
+            ("green", 13),
+            ("blue", 14),
+        ]);
+
+        let mut sum: i32 = 0;
+        for game_state in game_states {
+            if let Some(game_id) = possible_game_id(game_state, &limit_map)? {
+                sum += game_id
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, game_states: &Vec<GameState>) -> Result<Answer, Error> {
+        Ok(game_states.iter().map(|game_state| minimum_limit(&game_state.1)).sum::<i32>().into())
+    }
+}
+
+fn possible_game_id(game_state: &GameState, limit_map: &HashMap<&str, i32>) -> Result<Option<i32>, Error> {
+    for round_state in &game_state.1 {
+        if !is_possible_state(round_state, limit_map)? {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(game_state.0))
+}
+
+pub type GameState = (i32, Vec<HashMap<String, i32>>);
+
+fn parse_game_state(line: &str) -> Result<GameState, Error> {
+    let (header, state_str) = line.split_once(": ").context("missing line header 'game <id>:'")?;
+
+    let game_id_str = RE_ID.captures(header).context("no line header match")?.extract::<1>().1[0];
+    let game_id = game_id_str.parse::<i32>().context("parse game  id")?;
+
+    let mut result = Vec::<HashMap<String, i32>>::new();
+    for round_str in state_str.split("; ") {
+        let mut round_state = HashMap::<String, i32>::new();
+        for color_state_str in round_str.split(", ") {
+            let (amount_str, color) = color_state_str.split_once(" ").context("no space in color state")?;
+            let amount = amount_str.parse::<i32>().context("parse color")?;
+            round_state.insert(color.to_string(), amount);
+        }
+
+        result.push(round_state);
+    }
+
+    Ok((game_id, result))
+}
+
+fn is_possible_state(actual: &HashMap<String, i32>, limit: &HashMap<&str, i32>) -> Result<bool, Error> {
+    for (actual_color, actual_amount) in actual {
+        let limit_amount = limit.get(actual_color.as_str()).context("unknown color")?;
+        if limit_amount < actual_amount {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn minimum_limit(game_state: &[HashMap<String, i32>]) -> i32 {
+    let mut minimum_state = HashMap::<&str, i32>::new();
+    for round_state in game_state {
+        for (color, amount) in round_state {
+            if amount > minimum_state.get(color.as_str()).unwrap_or(&0) {
+                minimum_state.insert(color, *amount);
+            }
+        }
+    }
+
+    let mut product = 1;
+    for (_, amount) in minimum_state {
+        product *= amount;
+    }
+
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 8.into());
+        assert_eq!(Day.part2(&input).unwrap(), 2286.into());
+    }
+}
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
-use std::io;
 use std::rc::Rc;
 use anyhow::{Result, Ok, Context, bail};
+use lib::math;
+use lib::solution::{Answer, Solution};
 
 #[derive(Clone, Copy, Debug)]
 enum Pulse {
@@ -26,10 +27,12 @@ enum ModuleInputInfo {
     Id(usize),
 }
 
-struct Puzzle {
+#[derive(Clone)]
+pub struct Puzzle {
     modules: HashMap<Rc<str>, Module>,
 }
 
+#[derive(Clone)]
 struct Module {
     outputs: Vec<PulseTarget>,
     module_type: ModuleType,
@@ -78,88 +81,116 @@ impl Module {
     }
 }
 
+#[derive(Clone)]
 enum ModuleType {
     Broadcast,
     FlipFlop(FlipFlop),
     Nand(Nand),
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct FlipFlop {
     state: bool,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct Nand {
     input_lasts: Vec<Pulse>,
 }
 
-fn main() -> Result<()> {
-    let mut puzzle = parse()?;
+#[derive(Default)]
+pub struct Day;
 
-    let mut pulsed_low: i64 = 0;
-    let mut pulsed_high: i64 = 0;
-    let button_output = PulseTarget { target_module: "broadcaster".into(), input_info: ModuleInputInfo::None };
-    for button_press in 0i64.. {
-        let mut current_to_process = vec![PulseEvent {
-            pulse: Pulse::Low,
-            pulse_target: button_output.clone(),
-        }];
-        while !current_to_process.is_empty() {
-            let mut next_to_process = vec![];
-            for current in current_to_process {
-                let pulse_target = current.pulse_target;
-                match current.pulse {
-                    Pulse::High => {
-                        // this is NAND with 4 inputs that outputs to rx
-                        if pulse_target.target_module.as_ref() == "kh" {
-                            if let ModuleInputInfo::Id(id) = pulse_target.input_info {
-                                println!("input {} high in button_press {}", id, button_press + 1);
-                                // Figure out the lcm outside of code.
-                                // Cycles apparently all start at 0.
-                            }
-                        }
-                        pulsed_high += 1;
-                    }
-                    Pulse::Low => {
-                        if pulse_target.target_module.as_ref() == "rx" { // too large, never finishes
-                            println!("{}", button_press + 1);
-                            break;
-                        }
-                        pulsed_low += 1;
-                    }
-                };
-                let Some(module) = puzzle.modules.get_mut(&pulse_target.target_module)
-                    else {
-                        continue;
-                    };
-                if let Some(output_pulse) = module.process_pulse(current.pulse, &pulse_target.input_info)? {
-                    for output in &module.outputs {
-                        next_to_process.push(PulseEvent {
-                            pulse: output_pulse,
-                            pulse_target: output.clone(),
-                        })
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse(input)
+    }
+
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let mut puzzle = puzzle.clone();
+
+        let mut pulsed_low: i64 = 0;
+        let mut pulsed_high: i64 = 0;
+        for _ in 0..1000 {
+            press_button(&mut puzzle, |event| match event.pulse {
+                Pulse::High => pulsed_high += 1,
+                Pulse::Low => pulsed_low += 1,
+            })?;
+        }
+
+        Ok((pulsed_high * pulsed_low).into())
+    }
+
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let mut puzzle = puzzle.clone();
+
+        // rx is fed by a single NAND, that sends low only once all of its inputs were high.
+        // Each input goes high periodically, starting at 0, so the answer is lcm of those periods.
+        let (nand_name, nand_inputs) = puzzle.modules
+            .iter()
+            .find(|(_, module)| module.outputs.iter().any(|output| output.target_module.as_ref() == "rx"))
+            .and_then(|(name, module)| match &module.module_type {
+                ModuleType::Nand(nand) => Some((name.clone(), nand.input_lasts.len())),
+                _ => None,
+            })
+            .context("rx is not fed by a nand module")?;
+
+        let mut first_high: Vec<Option<i64>> = vec![None; nand_inputs];
+        for button_press in 1i64.. {
+            press_button(&mut puzzle, |event| {
+                if let (Pulse::High, ModuleInputInfo::Id(id)) = (event.pulse, event.pulse_target.input_info) {
+                    if event.pulse_target.target_module == nand_name && first_high[id].is_none() {
+                        first_high[id] = Some(button_press);
                     }
                 }
+            })?;
+            if first_high.iter().all(Option::is_some) {
+                break;
             }
-            current_to_process = next_to_process;
         }
-    }
 
-    println!("{}", pulsed_high * pulsed_low);
+        Ok(math::lcm_all(first_high.into_iter().flatten()).context("periods overflow")?.into())
+    }
+}
 
+fn press_button(puzzle: &mut Puzzle, mut on_pulse: impl FnMut(&PulseEvent)) -> Result<()> {
+    let button_output = PulseTarget { target_module: "broadcaster".into(), input_info: ModuleInputInfo::None };
+    let mut current_to_process = vec![PulseEvent {
+        pulse: Pulse::Low,
+        pulse_target: button_output,
+    }];
+    while !current_to_process.is_empty() {
+        let mut next_to_process = vec![];
+        for current in current_to_process {
+            on_pulse(&current);
+            let pulse_target = current.pulse_target;
+            let Some(module) = puzzle.modules.get_mut(&pulse_target.target_module)
+                else {
+                    continue;
+                };
+            if let Some(output_pulse) = module.process_pulse(current.pulse, &pulse_target.input_info)? {
+                for output in &module.outputs {
+                    next_to_process.push(PulseEvent {
+                        pulse: output_pulse,
+                        pulse_target: output.clone(),
+                    })
+                }
+            }
+        }
+        current_to_process = next_to_process;
+    }
     Ok(())
 }
 
-fn parse() -> Result<Puzzle> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<Puzzle> {
     let mut puzzle = Puzzle {
         modules: Default::default(),
     };
     let mut outputs_map = HashMap::<Rc<str>, Vec<Box<str>>>::new();
-    for line in stdin.lines() {
-        let line = line?;
-        let mut s = line.as_str();
+    for line in input.lines() {
+        let mut s = line;
         let module_type = match s.chars().next().context("unexpected empty line")? {
             '%' => {
                 s = s.strip_prefix('%').unwrap();
@@ -182,7 +213,7 @@ fn parse() -> Result<Puzzle> {
             name,
             outputs
                 .into_iter()
-                .map(|s| Box::<str>::from(s))
+                .map(Box::<str>::from)
                 .collect(),
         );
     }
@@ -205,3 +236,35 @@ fn parse() -> Result<Puzzle> {
     }
     Ok(puzzle)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    const EXAMPLE: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+    const EXAMPLE_OUTPUT: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    // part 2 needs the rx module of the real input
+    #[rstest]
+    #[case(EXAMPLE, 32000000)]
+    #[case(EXAMPLE_OUTPUT, 11687500)]
+    fn test_example_part1(#[case] example: &str, #[case] expected: u64) {
+        let input = Day.parse(example).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), expected.into());
+    }
+}
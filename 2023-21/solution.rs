@@ -0,0 +1,303 @@
+use std::collections::{HashMap, HashSet};
+use anyhow::{Context, Result, Ok};
+use lib::grid::{Coord, Grid, Offset, Wrapping, DIRECTIONS_CARDINAL, OFFSET_DOWN, OFFSET_LEFT, OFFSET_RIGHT, OFFSET_UP};
+use lib::grid_tile;
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
+
+pub struct Puzzle {
+    map: Grid<Point>,
+    start: Coord,
+}
+
+#[derive(PartialEq, Copy, Clone, Hash, Eq)]
+enum Point {
+    Garden,
+    Rock,
+}
+
+grid_tile!(Point {
+    '.' => Point::Garden,
+    '#' => Point::Rock,
+});
+
+pub struct Day {
+    // steps to take in part 1, and on the infinitely repeated map in part 2
+    steps: i64,
+    steps_repeated: i64,
+}
+
+impl Default for Day {
+    fn default() -> Self {
+        Day {
+            steps: 64,
+            steps_repeated: 26501365,
+        }
+    }
+}
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse(input)
+    }
+
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let reachable_exactly_in = self.steps;
+        let map = puzzle.map.wrapping().context("empty map")?;
+        let mut positions: HashSet<Coord> = [puzzle.start].into();
+        for _ in 0..reachable_exactly_in {
+            positions = move_from_positions(map, &positions);
+        }
+
+        Ok(positions.len().into())
+    }
+
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let reachable_exactly_in = self.steps_repeated;
+        let map = puzzle.map.wrapping().context("empty map")?;
+        assert_eq!(puzzle.map.rows(), puzzle.map.columns());
+        let across = puzzle.map.rows() as i64;
+        assert_eq!(across % 2, 1);
+        let mid = across / 2;
+        assert_eq!(puzzle.start, Coord { x: mid, y: mid });
+
+        let mut extended_positions_reachability: HashMap<Offset, HashMap<Coord, i64>> = Default::default();
+        for offset in [
+            Offset { x: 0, y: 0 },
+            OFFSET_UP,
+            OFFSET_UP + OFFSET_RIGHT,
+            OFFSET_RIGHT,
+            OFFSET_RIGHT + OFFSET_DOWN,
+            OFFSET_DOWN,
+            OFFSET_DOWN + OFFSET_LEFT,
+            OFFSET_LEFT,
+            OFFSET_LEFT + OFFSET_UP,
+        ] {
+            extended_positions_reachability.insert(offset, Default::default());
+            if offset.x == 0 && offset.y == 0 {
+                extended_positions_reachability.get_mut(&offset).unwrap().insert(puzzle.start, 0);
+            }
+        }
+
+        for i in 0.. {
+            let prev_extended_positions_reachability: HashMap<Offset, HashMap<Coord, i64>> = extended_positions_reachability.clone();
+            for (offset, offset_reachability) in prev_extended_positions_reachability.clone() {
+                let mut from_coords: HashSet<Coord> = Default::default();
+                for (reachable, reachable_in) in offset_reachability {
+                    if reachable_in != i {
+                        continue;
+                    }
+                    from_coords.insert(reachable);
+                }
+                let unbounded_to_coords = move_from_positions_unbounded(map, &from_coords);
+                for reached in unbounded_to_coords {
+                    let (wrapped_coord, wrapped_offset) = map.split(reached);
+                    let sum_offset = offset + wrapped_offset;
+                    if sum_offset.x.abs() >= 2 || sum_offset.y.abs() >= 2 {
+                        continue;
+                    }
+                    extended_positions_reachability.get_mut(&sum_offset).unwrap().entry(wrapped_coord).or_insert(i + 1);
+                }
+            }
+            if prev_extended_positions_reachability == extended_positions_reachability {
+                break;
+            }
+        }
+
+        let mut sum = 0;
+        // center submap
+        for &reachable_in in extended_positions_reachability.get(&Offset { x: 0, y: 0 }).unwrap().values() {
+            if reachable_in <= reachable_exactly_in && (reachable_exactly_in - reachable_in) % 2 == 0 {
+                sum += 1
+            }
+        }
+        // in cardinal directions
+        for offset in [OFFSET_UP, OFFSET_RIGHT, OFFSET_DOWN, OFFSET_LEFT] {
+            for &reachable_in in extended_positions_reachability.get(&offset).unwrap().values() {
+                if reachable_in > reachable_exactly_in {
+                    continue;
+                }
+                let diff = reachable_exactly_in - reachable_in;
+
+                sum += cardinal_count(diff, across);
+            }
+        }
+        // in diagonal directions
+        for offset in [
+            OFFSET_UP + OFFSET_RIGHT,
+            OFFSET_RIGHT + OFFSET_DOWN,
+            OFFSET_DOWN + OFFSET_LEFT,
+            OFFSET_LEFT + OFFSET_UP,
+        ] {
+            for &reachable_in in extended_positions_reachability.get(&offset).unwrap().values() {
+                if reachable_in > reachable_exactly_in {
+                    continue;
+                }
+                let diff = reachable_exactly_in - reachable_in;
+                sum += diagonal_count(diff, across);
+            }
+        }
+
+        Ok(sum.into())
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new()
+            .with("steps", &mut self.steps)
+            .with("steps_repeated", &mut self.steps_repeated)
+    }
+}
+
+fn move_from_positions(map: Wrapping<'_, Point>, positions: &HashSet<Coord>) -> HashSet<Coord> {
+    move_from_positions_unbounded(map, positions).into_iter().filter(|&x| map.grid().is_valid(x)).collect()
+}
+
+// moves on the map repeated infinitely in every direction
+fn move_from_positions_unbounded(map: Wrapping<'_, Point>, positions: &HashSet<Coord>) -> HashSet<Coord> {
+    let mut result = HashSet::<Coord>::default();
+    for &from_coord in positions {
+        for (to_coord, point) in map.neighbours(from_coord, &DIRECTIONS_CARDINAL) {
+            if matches!(point, Point::Garden) {
+                result.insert(to_coord);
+            }
+        }
+    }
+    result
+}
+
+fn parse(input: &str) -> Result<Puzzle> {
+    let (map, markers) = Grid::parse_str_with_markers(input, &[('S', '.')])?;
+    Ok(Puzzle {
+        map,
+        start: markers[&'S'],
+    })
+}
+
+fn cardinal_count(diff: i64, across: i64) -> i64 {
+    // max number of submaps this point is reachable in that direction
+    // S01234
+    let reachable_in_submap_count = diff / across;
+    let parity = (reachable_in_submap_count + diff) % 2;
+    ((reachable_in_submap_count + parity) / 2) + ((parity + 1) % 2)
+}
+
+fn diagonal_count(diff: i64, across: i64) -> i64 {
+    // number this point is reachable in that diagonal direction
+    // .43456
+    // .34567
+    // .23456
+    // .12345
+    // .01234
+    // S....
+    let reachable_in_diagonal = diff / across;
+    let parity = (reachable_in_diagonal + diff % across) % 2;
+
+    // let reachable_in_diagonal be n;
+    //
+    // for parity 0:
+    // result = 1 + 3 + 5 + 7 ... (n/2+1 items)
+    // let x = n/2+1
+    // result = 1 + 3 + 5 + 7 ... (x items)
+    // result = -x + (2 + 4 + 6 + 8 ... (x items))
+    // result = -x + 2 * (x * (x+1) / 2)
+    // result = -x + (x * (x + 1))
+    // result = -x + (x * (x + 1))
+    // result = x * x
+    //
+    // for parity 1:
+    // result = 2 + 4 + 6 + 8 ... ((n+1)/2 items)
+    // result = 2 * (1 + 2 + 3 + 4 ... ((n+1)/2 items)))
+    // let x = (n+1)/2
+    // result = 2 * (x * (x+1) / 2)
+    // result = x * (x+1)
+    //
+    match parity {
+        0 => {
+            let x = reachable_in_diagonal / 2 + 1;
+            x * x
+        }
+        1 => {
+            let x = (reachable_in_diagonal+1) / 2;
+            x * (x + 1)
+        }
+        _ => unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::{Day, cardinal_count, diagonal_count};
+    use lib::solution::Solution;
+
+    #[rstest]
+    #[case(0, 1, 1)]
+    #[case(1, 1, 1)]
+    #[case(2, 1, 2)]
+    #[case(3, 1, 2)]
+    #[case(4, 1, 3)]
+    #[case(5, 1, 3)]
+    #[case(0, 3, 1)]
+    #[case(1, 3, 0)]
+    #[case(2, 3, 1)]
+    #[case(3, 3, 1)]
+    #[case(4, 3, 1)]
+    #[case(5, 3, 1)]
+    #[case(6, 3, 2)]
+    #[case(7, 3, 1)]
+    #[case(0, 131, 1)]
+    #[case(131, 131, 1)]
+    #[case(132, 131, 1)]
+    #[case(262, 131, 2)]
+    #[case(263, 131, 1)]
+    fn test_cardinal_count(#[case] diff: i64, #[case] across: i64, #[case] expected: i64) {
+        assert_eq!(cardinal_count(diff, across), expected)
+    }
+
+    #[rstest]
+    #[case(0, 1, 1)]
+    #[case(1, 1, 2)]
+    #[case(2, 1, 4)]
+    #[case(3, 1, 6)]
+    #[case(4, 1, 9)]
+    #[case(5, 1, 12)]
+    #[case(0, 3, 1)]
+    #[case(1, 3, 0)]
+    #[case(2, 3, 1)]
+    #[case(3, 3, 2)]
+    #[case(4, 3, 1)]
+    #[case(5, 3, 2)]
+    #[case(6, 3, 4)]
+    #[case(7, 3, 2)]
+    #[case(0, 131, 1)]
+    #[case(131, 131, 2)]
+    #[case(132, 131, 1)]
+    #[case(262, 131, 4)]
+    #[case(263, 131, 2)]
+    fn test_diagonal_count(#[case] diff: i64, #[case] across: i64, #[case] expected: i64) {
+        assert_eq!(diagonal_count(diff, across), expected)
+    }
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    // part 2 relies on the empty middle row and column of the real input
+    #[test]
+    fn test_example_part1() {
+        let day = Day { steps: 6, ..Day::default() };
+        assert_eq!(day.part1(&day.parse(EXAMPLE).unwrap()).unwrap(), 16.into());
+    }
+}
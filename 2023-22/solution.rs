@@ -1,12 +1,12 @@
 use std::cmp::{max, min};
-use std::io;
 use std::ops::{RangeInclusive};
 use anyhow::{Result, Ok, Context};
+use lib::solution::{Answer, Solution};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-struct State {
+pub struct State {
     bricks: Vec<Brick>,
 }
 
@@ -76,33 +76,37 @@ struct Brick {
     z: RangeInclusive<i64>,
 }
 
-fn main() -> Result<()> {
-    Lazy::force(&RE);
+#[derive(Default)]
+pub struct Day;
 
-    let mut state = parse()?;
+impl Solution for Day {
+    type Input = State;
 
-    state.fall();
-
-    let sum = state.can_disintegrate();
-    println!("{sum}");
+    fn parse(&self, input: &str) -> Result<State> {
+        let mut state = parse(input)?;
+        state.fall();
+        Ok(state)
+    }
 
-    let mut sum = 0;
-    for i in 0..state.bricks.len() {
-        sum += state.supports_total(i)
+    fn part1(&self, state: &State) -> Result<Answer> {
+        Ok(state.can_disintegrate().into())
     }
-    println!("{sum}");
 
-    Ok(())
+    fn part2(&self, state: &State) -> Result<Answer> {
+        let mut sum = 0;
+        for i in 0..state.bricks.len() {
+            sum += state.supports_total(i)
+        }
+        Ok(sum.into())
+    }
 }
 
 static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\d+),(\d+),(\d+)~(\d+),(\d+),(\d+)").unwrap());
 
-fn parse() -> Result<State> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<State> {
     let mut bricks: Vec<Brick> = Vec::new();
-    for line in stdin.lines() {
-        let line = line?;
-        let (_, groups) = RE.captures(&line).context("invalid line")?.extract::<6>();
+    for line in input.lines() {
+        let (_, groups) = RE.captures(line).context("invalid line")?.extract::<6>();
         let nums = groups.iter().map(|x| Ok(x.parse::<i64>()?)).collect::<Result<Vec<_>>>()?;
         bricks.push(Brick {
             x: nums[0]..=nums[3],
@@ -114,3 +118,26 @@ fn parse() -> Result<State> {
         bricks,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 5.into());
+        assert_eq!(Day.part2(&input).unwrap(), 7.into());
+    }
+}
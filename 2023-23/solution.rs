@@ -1,11 +1,11 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
-use std::io;
 use std::ops::Index;
 use anyhow::{Result, Ok, bail, Context};
+use lib::solution::{Answer, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     map: Map,
     start: Coord,
 }
@@ -63,89 +63,90 @@ static LEFT: Offset = Offset { x: -1, y: 0 };
 static DOWN: Offset = Offset { x: 0, y: 1 };
 static RIGHT: Offset = Offset { x: 1, y: 0 };
 
-fn main() -> Result<()> {
-    let puzzle = parse()?;
+#[derive(Default)]
+pub struct Day;
 
-    let l = find_longest(&puzzle);
+impl Solution for Day {
+    type Input = Puzzle;
 
-    println!("{}", l);
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse(input)
+    }
 
-    let finish = Coord {
-        x: puzzle.map.points[(puzzle.map.rows - 1) * puzzle.map.columns..puzzle.map.points.len()]
-            .iter()
-            .enumerate()
-            .find(|(_, &p)| matches!(p, Point::Path))
-            .context("no finish found in last row")?
-            .0 as i64,
-        y: puzzle.map.rows as i64 - 1,
-    };
-
-    let mut crossroads: HashSet<Coord> = [puzzle.start, finish].into();
-    for y in 0..puzzle.map.rows {
-        for x in 0..puzzle.map.columns {
-            let point = puzzle.map.index(x, y);
-            if matches!(point, Point::Forest) {
-                continue;
-            }
-            let coord = Coord { x: x as i64, y: y as i64 };
-            let mut ways = 0;
-            for offset in [LEFT, UP, RIGHT, DOWN] {
-                let next_coord = coord.offset(offset);
-                if !puzzle.map.is_valid(&next_coord) || matches!(puzzle.map.index_coord(&next_coord), Point::Forest) {
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        Ok(find_longest(puzzle).into())
+    }
+
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let finish = Coord {
+            x: puzzle.map.points[(puzzle.map.rows - 1) * puzzle.map.columns..puzzle.map.points.len()]
+                .iter()
+                .enumerate()
+                .find(|(_, &p)| matches!(p, Point::Path))
+                .context("no finish found in last row")?
+                .0 as i64,
+            y: puzzle.map.rows as i64 - 1,
+        };
+
+        let mut crossroads: HashSet<Coord> = [puzzle.start, finish].into();
+        for y in 0..puzzle.map.rows {
+            for x in 0..puzzle.map.columns {
+                let point = puzzle.map.index(x, y);
+                if matches!(point, Point::Forest) {
                     continue;
                 }
-                ways += 1;
-            }
-            if ways >= 3 {
-                crossroads.insert(coord);
+                let coord = Coord { x: x as i64, y: y as i64 };
+                let mut ways = 0;
+                for offset in [LEFT, UP, RIGHT, DOWN] {
+                    let next_coord = coord.offset(offset);
+                    if !puzzle.map.is_valid(&next_coord) || matches!(puzzle.map.index_coord(&next_coord), Point::Forest) {
+                        continue;
+                    }
+                    ways += 1;
+                }
+                if ways >= 3 {
+                    crossroads.insert(coord);
+                }
             }
         }
-    }
 
-    let mut from_crossroad: HashMap<Coord, Vec<(Coord, i64)>> = Default::default();
+        let mut from_crossroad: HashMap<Coord, Vec<(Coord, i64)>> = Default::default();
 
-    for coord in &crossroads {
-        for (neighbor_crossroad, distance) in neighboring_crossroads_distances(&crossroads, &puzzle.map, coord) {
-            from_crossroad.entry(
-                *coord,
-            ).or_default().push((neighbor_crossroad, distance));
+        for coord in &crossroads {
+            for (neighbor_crossroad, distance) in neighboring_crossroads_distances(&crossroads, &puzzle.map, coord) {
+                from_crossroad.entry(
+                    *coord,
+                ).or_default().push((neighbor_crossroad, distance));
+            }
         }
-    }
-
-    println!("nodes: {}", crossroads.len());
-    println!("edges: {}", from_crossroad.len());
 
-    let mut max_distance = 0;
+        let mut max_distance = 0;
 
-    let visited_crossroads: RefCell<HashSet<Coord>> = Default::default();
-    let mut total_hike_count: i64 = 0;
+        let visited_crossroads: RefCell<HashSet<Coord>> = Default::default();
 
-    dfs(
-        (puzzle.start, 0),
-        |to_expand| {
-            visited_crossroads.borrow_mut().insert(to_expand.0);
-            if to_expand.0 == finish {
-                total_hike_count += 1;
-                if to_expand.1 > max_distance {
-                    max_distance = to_expand.1;
-                    println!("next max: {}, currently found {} hikes", max_distance, total_hike_count);
+        dfs(
+            (puzzle.start, 0),
+            |to_expand| {
+                visited_crossroads.borrow_mut().insert(to_expand.0);
+                if to_expand.0 == finish {
+                    if to_expand.1 > max_distance {
+                        max_distance = to_expand.1;
+                    }
+                    return [].into();
                 }
-                return [].into();
-            }
-            let expanded_coords = from_crossroad.get(&to_expand.0).unwrap();
-            expanded_coords
-                .iter()
-                .filter(|c| !visited_crossroads.borrow().contains(&c.0))
-                .map(|&(coord, distance)| (coord, to_expand.1 + distance))
-                .collect()
-        }, |to_revert| {
-            visited_crossroads.borrow_mut().remove(&to_revert.0);
-        },
-    );
-
-    println!("{}, found {} hikes", max_distance, total_hike_count);
+                let expanded_coords = from_crossroad.get(&to_expand.0).unwrap();
+                expanded_coords
+                    .iter()
+                    .filter(|c| !visited_crossroads.borrow().contains(&c.0))
+                    .map(|&(coord, distance)| (coord, to_expand.1 + distance))
+                    .collect()
+            }, |to_revert| {
+                visited_crossroads.borrow_mut().remove(&to_revert.0);
+            },
+        );
 
-    Ok(())
+        Ok(max_distance.into())
+    }
 }
 
 fn neighboring_crossroads_distances(crossroads: &HashSet<Coord>, map: &Map, from_coord: &Coord) -> Vec<(Coord, i64)> {
@@ -262,14 +263,12 @@ fn dfs<TState, TExpandFn, TRevertFn>(
     }
 }
 
-fn parse() -> Result<Puzzle> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<Puzzle> {
     let mut points: Vec<Point> = Vec::new();
     let mut rows: usize = 0;
     let mut columns = 0;
     let mut start: Option<Coord> = None;
-    for (row, line) in stdin.lines().enumerate() {
-        let line = line?;
+    for (row, line) in input.lines().enumerate() {
         columns = line.chars().count();
         rows += 1;
 
@@ -303,4 +302,43 @@ fn parse() -> Result<Puzzle> {
         },
         start: start.context("no start found")?,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 94.into());
+        assert_eq!(Day.part2(&input).unwrap(), 154.into());
+    }
+}
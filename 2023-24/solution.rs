@@ -1,6 +1,7 @@
-use std::io;
 use std::ops::{Add, Mul, Sub};
 use anyhow::{Result, Ok, Context, bail};
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
 use mathru::{
     algebra::linear::{
         matrix::{General, Solve},
@@ -95,7 +96,7 @@ impl D3 {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct State {
+pub struct State {
     position: D3,
     velocity: D3,
 }
@@ -122,53 +123,79 @@ impl Sub for State {
     }
 }
 
-fn main() -> Result<()> {
-    let states = parse()?;
+pub struct Day {
+    // area of x and y coordinates where part 1 collisions count
+    test_area_min: f64,
+    test_area_max: f64,
+}
 
-    let test_area_bounds = 200000000000000f64..=400000000000000f64;
+impl Default for Day {
+    fn default() -> Self {
+        Day {
+            test_area_min: 200000000000000f64,
+            test_area_max: 400000000000000f64,
+        }
+    }
+}
 
-    let mut collisions = 0;
+impl Solution for Day {
+    type Input = Vec<State>;
 
-    for (i, state1) in states.iter().enumerate() {
-        for state2 in &states[i + 1..] {
-            let intersection = path_intersection_2d(state1, state2);
-            let Some(intersection) = intersection else {
-                continue;
-            };
-            if intersection.time.iter().any(|&t| t < 0f64) {
-                continue;
-            }
-            if intersection.position.iter().any(|p| !test_area_bounds.contains(p)) {
-                continue;
-            }
-            collisions += 1;
-        }
+    fn parse(&self, input: &str) -> Result<Vec<State>> {
+        parse(input)
     }
 
-    println!("{}", collisions);
+    fn part1(&self, states: &Vec<State>) -> Result<Answer> {
+        let mut collisions = 0;
 
-    for (i, &state1) in states.iter().enumerate() {
-        for (j, &state2) in states.iter().enumerate() {
-            for (k, &state3) in states.iter().enumerate() {
-                if i == j || i == k || j == k {
+        for (i, state1) in states.iter().enumerate() {
+            for state2 in &states[i + 1..] {
+                let intersection = path_intersection_2d(state1, state2);
+                let Some(intersection) = intersection else {
+                    continue;
+                };
+                if intersection.time.iter().any(|&t| t < 0f64) {
                     continue;
                 }
-                let rock = hit_all(vec![state1, state2, state3].as_slice());
-                if (rock.position.x.round() - rock.position.x).abs() < f64::EPSILON &&
-                    (rock.position.y.round() - rock.position.y).abs() < f64::EPSILON &&
-                    (rock.position.z.round() - rock.position.z).abs() < f64::EPSILON &&
-                    (rock.velocity.x.round() - rock.velocity.x).abs() < f64::EPSILON &&
-                    (rock.velocity.y.round() - rock.velocity.y).abs() < f64::EPSILON &&
-                    (rock.velocity.z.round() - rock.velocity.z).abs() < f64::EPSILON {
-                    println!("{} {} {} {:?}", i, j, k, rock);
-                    println!("{:?}", rock.position.sum());
-                    return Ok(())
+                let test_area = self.test_area_min..=self.test_area_max;
+                if intersection.position.iter().any(|p| !test_area.contains(p)) {
+                    continue;
                 }
+                collisions += 1;
             }
         }
+
+        Ok(collisions.into())
     }
 
-    Ok(())
+    fn part2(&self, states: &Vec<State>) -> Result<Answer> {
+        for (i, &state1) in states.iter().enumerate() {
+            for (j, &state2) in states.iter().enumerate() {
+                for (k, &state3) in states.iter().enumerate() {
+                    if i == j || i == k || j == k {
+                        continue;
+                    }
+                    let rock = hit_all(vec![state1, state2, state3].as_slice());
+                    if (rock.position.x.round() - rock.position.x).abs() < f64::EPSILON &&
+                        (rock.position.y.round() - rock.position.y).abs() < f64::EPSILON &&
+                        (rock.position.z.round() - rock.position.z).abs() < f64::EPSILON &&
+                        (rock.velocity.x.round() - rock.velocity.x).abs() < f64::EPSILON &&
+                        (rock.velocity.y.round() - rock.velocity.y).abs() < f64::EPSILON &&
+                        (rock.velocity.z.round() - rock.velocity.z).abs() < f64::EPSILON {
+                        return Ok((rock.position.sum() as i64).into())
+                    }
+                }
+            }
+        }
+
+        bail!("no integer rock throw found")
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new()
+            .with("test_area_min", &mut self.test_area_min)
+            .with("test_area_max", &mut self.test_area_max)
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -253,11 +280,9 @@ fn path_intersection_2d(state1: &State, state2: &State) -> Option<Intersection2D
     })
 }
 
-fn parse() -> Result<Vec<State>> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<Vec<State>> {
     let mut states: Vec<State> = Vec::new();
-    for line in stdin.lines() {
-        let line = line?;
+    for line in input.lines() {
         let (positions, velocities) = line.split_once('@').context("missing @ in line")?;
         let [position, velocity] = [positions, velocities]
             .map(|s| {
@@ -274,4 +299,29 @@ fn parse() -> Result<Vec<State>> {
         })
     }
     Ok(states)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn test_example() {
+        let day = Day {
+            test_area_min: 7f64,
+            test_area_max: 27f64,
+        };
+        let input = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&input).unwrap(), 2.into());
+        assert_eq!(day.part2(&input).unwrap(), 47.into());
+    }
+}
@@ -1,33 +1,48 @@
 use std::collections::{HashMap, HashSet};
-use std::io;
 use std::rc::Rc;
-use anyhow::{Result, Ok, Context};
+use anyhow::{bail, Result, Ok, Context};
+use lib::solution::{Answer, Solution};
 use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
 
-type Graph = petgraph::Graph<NodeInfo, i64, petgraph::Undirected>;
+pub type Graph = petgraph::Graph<NodeInfo, i64, petgraph::Undirected>;
 
-#[derive(Debug)]
-struct NodeInfo {
+#[derive(Clone, Debug)]
+pub struct NodeInfo {
     names: HashSet<Rc<str>>,
 }
 
-fn main() -> Result<()> {
-    let mut graph = parse()?;
-
-    let orig_node_count = graph.node_count();
-    while graph.node_count() >= 2 {
-        // Stoer-Wagner min-cut algorithm
-        let (last_edge_weight, node_s, node_t) = cut_phase(&graph);
-        let last_node_cardinality = graph.node_weight(node_t).unwrap().names.len();
-        if last_edge_weight == 3 {
-            println!("{}", last_node_cardinality * (orig_node_count - last_node_cardinality));
-            break;
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Graph;
+
+    fn parse(&self, input: &str) -> Result<Graph> {
+        parse(input)
+    }
+
+    fn part1(&self, graph: &Graph) -> Result<Answer> {
+        let mut graph = graph.clone();
+
+        let orig_node_count = graph.node_count();
+        while graph.node_count() >= 2 {
+            // Stoer-Wagner min-cut algorithm
+            let (last_edge_weight, node_s, node_t) = cut_phase(&graph);
+            let last_node_cardinality = graph.node_weight(node_t).unwrap().names.len();
+            if last_edge_weight == 3 {
+                return Ok((last_node_cardinality * (orig_node_count - last_node_cardinality)).into());
+            }
+            merge_last_nodes(&mut graph, node_s, node_t);
         }
-        merge_last_nodes(&mut graph, node_s, node_t);
+
+        bail!("no cut of 3 wires found")
     }
 
-    Ok(())
+    fn part2(&self, _: &Graph) -> Result<Answer> {
+        // the last day has only one puzzle
+        Ok("-".into())
+    }
 }
 
 fn merge_last_nodes(graph: &mut Graph, mut node_s: NodeIndex, node_t: NodeIndex) {
@@ -102,12 +117,10 @@ fn cut_phase(graph: &Graph) -> (i64, NodeIndex, NodeIndex) {
     (cut_off_weight, node_s, node_t)
 }
 
-fn parse() -> Result<Graph> {
-    let stdin = io::stdin();
+fn parse(input: &str) -> Result<Graph> {
     let mut graph: Graph = Graph::new_undirected();
     let mut node_indexes = HashMap::<Rc<str>, NodeIndex>::new();
-    for line in stdin.lines() {
-        let line = line?;
+    for line in input.lines() {
         let (from_node, to_nodes) = line.split_once(": ").context("missing : in line")?;
         let to_nodes = to_nodes.split_whitespace().collect::<Vec<_>>();
         let mut ensure_node = |node: &str, from: Option<NodeIndex>| -> NodeIndex {
@@ -134,4 +147,32 @@ fn parse() -> Result<Graph> {
         }
     }
     Ok(graph)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 54.into());
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::{HashMap, HashSet};
+use anyhow::{Context, Error};
+use lib::solution::{Answer, Solution};
+
+pub struct Card {
+    winning: HashSet<u32>,
+    actual: HashSet<u32>,
+}
+
+impl Card {
+    fn winning_count(&self) -> u32 {
+        self.actual.intersection(&self.winning).count() as u32
+    }
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Card>, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_line(line).with_context(|| format!("parsing line {}", i + 1)))
+            .collect()
+    }
+
+    fn part1(&self, cards: &Vec<Card>) -> Result<Answer, Error> {
+        let mut sum = 0;
+        for card in cards {
+            let winning_count = card.winning_count();
+            let score = if winning_count > 0 { 2_u32.pow(winning_count - 1) } else { 0 };
+            sum += score;
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, cards: &Vec<Card>) -> Result<Answer, Error> {
+        let mut copies = HashMap::<usize, u32>::new(); // map card_index: won_copies (excludes original)
+        let mut sum = 0;
+        for (i, card) in cards.iter().enumerate() {
+            let winning_count = card.winning_count();
+
+            let current_card_instances = *copies.get(&i).unwrap_or(&0) + 1;
+            sum += current_card_instances;
+
+            for won_copy_index in i + 1..i + 1 + winning_count as usize {
+                let prev_copies = copies.get(&won_copy_index).unwrap_or(&0);
+                copies.insert(won_copy_index, prev_copies + current_card_instances);
+            }
+        }
+        Ok(sum.into())
+    }
+}
+
+fn parse_line(line: &str) -> Result<Card, Error> {
+    let (_, state_str) = line.split_once(": ").context("missing line header")?;
+    let (winning_str, actual_str) = state_str.split_once(" | ").context("missing winning/actual separator")?;
+
+    fn str_to_card_set(s: &str) -> Result<HashSet<u32>, Error> {
+        s.split_whitespace().
+            map(|x| Ok::<_, Error>(x.parse::<u32>()?)).
+            collect::<Result<_, _>>()
+    }
+
+    Ok(Card {
+        winning: str_to_card_set(winning_str).context("parsing winning card set")?,
+        actual: str_to_card_set(actual_str).context("parsing actual card set")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 13.into());
+        assert_eq!(Day.part2(&input).unwrap(), 30.into());
+    }
+}
@@ -0,0 +1,199 @@
+use anyhow::{bail, Context, Result, Ok};
+use lib::interval::{IntervalMap, IntervalSet};
+use lib::solution::{Answer, Solution};
+
+pub struct Input {
+    seeds: Vec<i64>,
+    mappings: Vec<Mappings>,
+}
+
+struct Mappings {
+    // offset of every mapped source range
+    mapping: IntervalMap<i64, i64>,
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Input;
+
+    fn parse(&self, input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Input) -> Result<Answer> {
+        let mapped = map_through_all(input);
+        Ok((*mapped.iter().min().context("no result")?).into())
+    }
+
+    fn part2(&self, input: &Input) -> Result<Answer> {
+        let mapped = map_ranges_through_all(input);
+        let lowest = mapped.iter().next().context("no result")?.start;
+        Ok(lowest.into())
+    }
+}
+
+fn map_through_all(input: &Input) -> Vec<i64> {
+    input.seeds.iter().map(|x| {
+        let mut x = *x;
+        for step in &input.mappings {
+            x = step.mapping.map(x)
+        }
+        x
+    }).collect()
+}
+
+fn map_ranges_through_all(input: &Input) -> IntervalSet<i64> {
+    let chunks = input.seeds.chunks_exact(2);
+    let mut current_ranges: IntervalSet<i64> = chunks.map(|x| x[0]..x[0] + x[1]).collect();
+
+    for mapping in &input.mappings {
+        current_ranges = mapping.mapping.map_set(&current_ranges);
+    }
+
+    current_ranges
+}
+
+fn parse(input: &str) -> Result<Input> {
+    let mut lines = input.lines();
+    let first = lines.next();
+    let seeds = first.
+        context("missing first line")?.
+        strip_prefix("seeds:").context("missing first line header")?.
+        split_whitespace().
+        map(|s| Ok(s.parse::<i64>()?)).
+        collect::<Result<Vec<_>>>().context("parsing first line")?;
+
+    let mut mapping: Vec<Mappings> = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with(char::is_alphabetic) {
+            let mapping_header_str = line.split_once(':').context("missing ':' in mapping header line")?.0;
+            mapping_header_str.split('-').nth(2).context("missing mapping destination")?;
+
+            mapping.push(Mappings {
+                mapping: IntervalMap::new(),
+            });
+            continue;
+        }
+
+        let nums: Vec<i64> = line.
+            split_whitespace().
+            map(|s| Ok(s.parse::<i64>()?)).
+            collect::<Result<_>>()?;
+        if nums.len() != 3 {
+            bail!("unexpected mapping length")
+        }
+
+        let start_dest = nums[0];
+        let start_src = nums[1];
+        let len = nums[2];
+        let end_src = start_src.checked_add(len).with_context(|| format!("mapping range too large, got '{}'", line))?;
+        let offset = start_dest.checked_sub(start_src).with_context(|| format!("mapping offset too large, got '{}'", line))?;
+        mapping.
+            last_mut().context("mapping nums before mapping header")?.
+            mapping.insert(start_src..end_src, offset);
+    }
+
+    Ok(Input {
+        seeds,
+        mappings: mapping,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy;
+    use super::Day;
+    use lib::interval::IntervalMap;
+    use lib::solution::Solution;
+
+    static RANGES_MAP: Lazy<IntervalMap<i64, i64>> = Lazy::new(|| {
+        let mut map = IntervalMap::new();
+        map.insert(50..60, 50);
+        map
+    });
+
+    #[test]
+    fn before_unmapped() {
+        assert_eq!(RANGES_MAP.map_range(45..50), vec![45..50])
+    }
+
+    #[test]
+    fn after_unmapped() {
+        assert_eq!(RANGES_MAP.map_range(60..65), vec![60..65])
+    }
+
+    #[test]
+    fn start_range_mapped() {
+        assert_eq!(RANGES_MAP.map_range(50..55), vec![100..105])
+    }
+
+    #[test]
+    fn end_range_mapped() {
+        assert_eq!(RANGES_MAP.map_range(55..60), vec![105..110])
+    }
+
+    #[test]
+    fn part_through_start() {
+        assert_eq!(RANGES_MAP.map_range(45..55), vec![45..50, 100..105])
+    }
+
+    #[test]
+    fn part_through_end() {
+        assert_eq!(RANGES_MAP.map_range(55..65), vec![105..110, 60..65])
+    }
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 35.into());
+        assert_eq!(Day.part2(&input).unwrap(), 46.into());
+    }
+
+    #[test]
+    fn test_overflowing_mapping() {
+        assert!(Day.parse("seeds: 1\n\nseed-to-soil map:\n0 9223372036854775807 1\n").is_err());
+        assert!(Day.parse("seeds: 1\n\nseed-to-soil map:\n-9223372036854775808 1 1\n").is_err());
+    }
+}
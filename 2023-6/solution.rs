@@ -0,0 +1,137 @@
+use anyhow::{bail, Result, Ok, Context};
+use lib::solution::{Answer, Solution};
+
+pub struct Input {
+    races: Vec<Race>,
+    single_race: Race,
+}
+
+struct Race {
+    best_time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn possible_ways_to_beat(&self) -> u64 {
+        // function, where wind_up_time is a variable
+        // distance = (best_time-wind_up_time) * speed
+        // distance = (best_time-wind_up_time) * wind_up_time
+        // therefore
+        // y = (best_time-x) * x
+        // y = -x^2 + best_time*x
+        // find where y > distance, so instead just move down by distance and find 0 intersects
+        // 0 = -x^2 + best_time*x - distance
+        // roots:
+        // det = best_time ^ 2 - 4 * (-1) * (-distance)
+        // det = best_time ^ 2 - 4*distance
+        // x1 = (-best_time + det) / (2*(-1))
+        // x2 = (-best_time - det) / (2*(-1))
+        let det: f64 = (self.best_time as f64 * self.best_time as f64 - 4_f64 * self.distance as f64).sqrt();
+        let x1: f64 = (-(self.best_time as f64) - det) / -2_f64;
+        let x2: f64 = (-(self.best_time as f64) + det) / -2_f64;
+        let lower = f64::min(x1,x2);
+        let upper = f64::max(x1,x2);
+        // small eps, To only capture races where I win, not tie. Also due to possible loss of precision
+        let lower = (lower + 0.000001).ceil() as u64;
+        let upper = (upper - 0.000001).trunc() as u64;
+        let upper = u64::min(self.best_time, upper);
+        upper - lower + 1
+    }
+
+    #[cfg(test)]
+    fn possible_ways_to_beat_slow(&self) -> u64 {
+        let mut ways = 0;
+        for wind_up_time in 0..=self.best_time {
+            let speed = wind_up_time;
+            let remaining_time = self.best_time - wind_up_time;
+            if remaining_time * speed > self.distance {
+                ways += 1;
+            }
+        }
+        ways
+    }
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Input;
+
+    fn parse(&self, input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Input) -> Result<Answer> {
+        let mut result = 1;
+        for race in &input.races {
+            result *= race.possible_ways_to_beat()
+        }
+        Ok(result.into())
+    }
+
+    fn part2(&self, input: &Input) -> Result<Answer> {
+        Ok(input.single_race.possible_ways_to_beat().into())
+    }
+}
+
+fn parse(input: &str) -> Result<Input> {
+    let metrics = ["Time", "Distance"];
+    let lines = input.lines();
+    let mut values = Vec::<Vec<u64>>::new();
+    let mut single_values = Vec::<u64>::new();
+    for (i, line) in lines.enumerate() {
+        let metric = metrics.get(i).context("unexpected extra line")?;
+        let stripped = line.strip_prefix(&[metric, ": "].join("")).context("line header missing")?;
+        // part1
+        let values_str = stripped.split_whitespace();
+        let line_values = values_str.map(|x| Ok(x.parse::<u64>()?)).collect::<Result<_>>()?;
+        values.push(line_values);
+        // part2
+        let value_str = stripped.replace(" ", "");
+        single_values.push(value_str.parse::<u64>()?);
+    }
+    if values.len() != 2 {
+        bail!("expected Time and Distance lines")
+    }
+    let mut result_vec: Vec<Race> = Vec::new();
+    for (&best_time, &distance) in values[0].iter().zip(&values[1]) {
+        result_vec.push(Race {
+            best_time,
+            distance,
+        })
+    }
+    Ok(Input {
+        races: result_vec,
+        single_race: Race {
+            best_time: single_values[0],
+            distance: single_values[1],
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day, Race};
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 288.into());
+        assert_eq!(Day.part2(&input).unwrap(), 71503.into());
+    }
+
+    #[test]
+    fn test_formula_matches_counting() {
+        for (best_time, distance) in [(7, 9), (15, 40), (30, 200), (71530, 940200), (10, 24)] {
+            let race = Race { best_time, distance };
+            assert_eq!(race.possible_ways_to_beat(), race.possible_ways_to_beat_slow(), "{best_time} {distance}");
+        }
+    }
+}
@@ -1,19 +1,25 @@
 use std::cmp::{Ordering, Reverse};
 use std::collections::HashMap;
-use std::io;
 use anyhow::{Result, Ok, Context};
+use lib::solution::{Answer, Solution};
 
 struct Play {
     hand: Hand,
     bet: u64,
 }
 
-#[derive(PartialOrd, PartialEq, Eq)]
+#[derive(PartialEq, Eq)]
 struct Hand {
     hand: Vec<Card>,
     type_: HandType,
 }
 
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
         self.type_.cmp(&other.type_).then(self.hand.cmp(&other.hand))
@@ -34,14 +40,14 @@ impl Hand {
                 jokers += 1;
                 continue
             }
-            card_counts.insert(*card, *card_counts.get(&card).unwrap_or(&0) + 1);
+            card_counts.insert(*card, *card_counts.get(card).unwrap_or(&0) + 1);
         }
         let mut card_counts_vec: Vec<(Card, u8)> = card_counts.into_iter().collect();
         card_counts_vec.sort_by_key(|x| Reverse(x.1));
-        if card_counts_vec.len() == 0 {
+        if card_counts_vec.is_empty() {
             card_counts_vec.push((Card::Joker, 5))
         } else {
-            card_counts_vec[0].1 = card_counts_vec[0].1 + jokers;
+            card_counts_vec[0].1 += jokers;
         }
         Ok(Hand {
             hand: result,
@@ -103,30 +109,51 @@ impl Card {
                 false => {Card::Jack}
             }
             'T' => Card::Ten,
-            n => Card::Num(n as u8 - '0' as u8),
+            n => Card::Num(n as u8 - b'0'),
         }
     }
 }
 
-fn main() -> Result<()> {
-    // bool parameter part 2
-    let mut input = parse(true)?;
+// the same hands, read once with 'J' as jacks (part 1) and once as jokers (part 2)
+pub struct Plays {
+    with_jacks: Vec<Play>,
+    with_jokers: Vec<Play>,
+}
 
-    input.sort_by(|v1, v2| v1.hand.cmp(&v2.hand));
-    let mut sum = 0;
-    for (i, play) in input.iter().enumerate() {
-        sum += (i as u64 + 1) * play.bet;
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Plays;
+
+    fn parse(&self, input: &str) -> Result<Plays> {
+        Ok(Plays {
+            with_jacks: parse(input, false)?,
+            with_jokers: parse(input, true)?,
+        })
     }
 
-    println!("{}", sum);
+    fn part1(&self, plays: &Plays) -> Result<Answer> {
+        Ok(total_winnings(&plays.with_jacks).into())
+    }
 
-    Ok(())
+    fn part2(&self, plays: &Plays) -> Result<Answer> {
+        Ok(total_winnings(&plays.with_jokers).into())
+    }
 }
 
-fn parse(with_jokers: bool) -> Result<Vec<Play>> {
-    let stdin = io::stdin();
-    stdin.lines().into_iter().map(|line| {
-        let line = line?;
+fn total_winnings(plays: &[Play]) -> u64 {
+    let mut plays: Vec<&Play> = plays.iter().collect();
+    plays.sort_by(|v1, v2| v1.hand.cmp(&v2.hand));
+    let mut sum = 0;
+    for (i, play) in plays.iter().enumerate() {
+        sum += (i as u64 + 1) * play.bet;
+    }
+    sum
+}
+
+fn parse(input: &str, with_jokers: bool) -> Result<Vec<Play>> {
+    input.lines().map(|line| {
         let split = line.split_once(" ").context("missing space in line")?;
         Ok(Play {
             hand: Hand::from_str(split.0, with_jokers)?,
@@ -139,7 +166,8 @@ fn parse(with_jokers: bool) -> Result<Vec<Play>> {
 mod tests {
     use rstest::rstest;
     use std::cmp::Ordering;
-    use crate::Hand;
+    use super::{Day, Hand};
+    use lib::solution::Solution;
 
     #[rstest]
     #[case("K32KK", "3K2KK")]
@@ -164,4 +192,19 @@ mod tests {
     fn sort_last() {
         assert_eq!(Hand::from_str("23456", false).unwrap().cmp(&Hand::from_str("23457", false).unwrap()), Ordering::Less)
     }
-}
\ No newline at end of file
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 6440.into());
+        assert_eq!(Day.part2(&input).unwrap(), 5905.into());
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::ptr::NonNull;
+use anyhow::{Result, Ok, Context, bail};
+use lib::{cycle, math};
+use lib::solution::{Answer, Solution};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+struct InputNode {
+    name: String,
+    left: String,
+    right: String,
+}
+
+pub struct Puzzle {
+    directions: Vec<Direction>,
+    desert: DesertMap,
+}
+
+struct DesertMap {
+    // 'static to trick borrow checker, together with unsafe in from_node_vec constructor:
+    // all Place references returned from other methods live as long as DesertMap.
+    // The only constraint to caller is that this places field is not be accessed directly
+    places: HashMap<String, Box<Place<'static>>>,
+}
+
+impl DesertMap {
+    fn from_node_vec(input_nodes: Vec<InputNode>) -> Result<DesertMap> {
+        let mut result = HashMap::<String, Box<Place>>::new();
+
+        for node in &input_nodes {
+            let name: String = node.name.clone();
+            let name_ptr = NonNull::from(name.as_str());
+            result.insert(name, Place {
+                name: unsafe { name_ptr.as_ref() },
+                left: None,
+                right: None,
+            }.into());
+        }
+
+        for node in &input_nodes {
+            let name = node.name.as_str();
+            let left_name = node.left.as_str();
+            let right_name = node.right.as_str();
+            let left_place = Some(match result.get(left_name) {
+                None => { bail!("unknow place  {}", left_name) }
+                Some(left_place) => { unsafe { NonNull::from((*left_place).as_ref()).as_ref() } }
+            });
+            let right_place = Some(match result.get(right_name) {
+                None => { bail!("unknow place  {}", right_name) }
+                Some(right_place) => { unsafe { NonNull::from((*right_place).as_ref()).as_ref() } }
+            });
+
+            match result.get_mut(name) {
+                None => { unreachable!("the name should have been inserted in loop above") }
+                Some(place) => {
+                    place.left = left_place;
+                    place.right = right_place;
+                }
+            }
+        }
+
+        Ok(DesertMap {
+            places: result,
+        })
+    }
+
+    fn navigate<'a>(&'a self, current_place: &'a Place, direction: &Direction) -> Result<&'a Place<'a>> {
+        Ok(match direction {
+            Direction::Left => current_place.left.context("uninitialized left place")?,
+            Direction::Right => current_place.right.context("uninitialized right place")?
+        })
+    }
+
+    fn get_by_name(&self, name: &str) -> Option<&Place<'_>> {
+        Some(self.places.get(name)?.as_ref())
+    }
+
+    fn iter<'a>(&'a self) -> Box<dyn Iterator<Item=&'a Place<'a>> + 'a> {
+        Box::new(self.places
+            .values()
+            .map(|x| (*x).as_ref())
+        )
+    }
+}
+
+struct Place<'a> {
+    name: &'a str,
+    left: Option<&'a Place<'a>>,
+    right: Option<&'a Place<'a>>,
+}
+
+impl<'a> Debug for Place<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name.as_ref() )
+    }
+}
+
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_char(c: &char) -> Option<Direction> {
+        match c {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None
+        }
+    }
+}
+
+static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(\w+) = \((\w+), (\w+)\)"#).unwrap());
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse(input)
+    }
+
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let mut current_place = puzzle.desert.get_by_name("AAA").context("missing starting place AAA")?;
+        let mut steps: u64 = 0;
+        for direction in puzzle.directions.iter().cycle() {
+            current_place = puzzle.desert.navigate(current_place, direction)?;
+            steps += 1;
+            if current_place.name == "ZZZ" {
+                break;
+            }
+        }
+
+        Ok(steps.into())
+    }
+
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let mut least_common_multiple = 1;
+        for start in puzzle.desert.iter().filter(|place| place.name.ends_with("A")) {
+            let interval = end_interval(puzzle, start)?;
+            least_common_multiple = math::lcm(least_common_multiple, interval).context("steps overflow")?;
+        }
+
+        Ok(least_common_multiple.into())
+    }
+}
+
+// steps between reaching places ending with Z from the start, which repeats once the place and the
+// position in directions do
+fn end_interval(puzzle: &Puzzle, start: &Place) -> Result<u64> {
+    let mut current_place = start;
+    let mut steps: u64 = 0;
+    let mut end_steps = vec![];
+    let mut error = None;
+    let states = std::iter::once((start.name, 0)).chain(std::iter::from_fn(|| {
+        let direction = &puzzle.directions[steps as usize % puzzle.directions.len()];
+        current_place = puzzle.desert.navigate(current_place, direction).map_err(|err| error = Some(err)).ok()?;
+        steps += 1;
+        if current_place.name.ends_with('Z') {
+            end_steps.push(steps);
+        }
+        Some((current_place.name, steps as usize % puzzle.directions.len()))
+    }));
+    let cycle = cycle::detect(states);
+    if let Some(err) = error {
+        return Err(err);
+    }
+    let cycle = cycle.context("navigation ended")?;
+    if end_steps.last().is_none_or(|&steps| steps < cycle.prefix as u64) {
+        bail!("{} does not keep reaching places ending with Z", start.name);
+    }
+
+    // the lcm of the intervals is the answer only when every start reaches the ends at whole multiples
+    // of its interval, the first one after a single interval, as the puzzle inputs are built
+    let interval = end_steps[0];
+    if end_steps.iter().zip(1..).any(|(&steps, n)| steps != n * interval) {
+        bail!("{} reaches ends after {end_steps:?} steps, not at multiples of one interval", start.name);
+    }
+    Ok(interval)
+}
+
+fn parse(input: &str) -> Result<Puzzle> {
+    let mut lines = input.lines();
+    let first_line = lines.next();
+    let directions: Vec<Direction> = first_line.context("no first line")?.chars().map(
+        |c| Ok(Direction::from_char(&c).context("invalid direction")?)
+    ).collect::<Result<_>>()?;
+    if directions.is_empty() {
+        bail!("no directions");
+    }
+    lines.next(); // consume empty line
+
+    let parsed_lines = lines.map(|line| {
+        let captures = RE.captures(line).context("no match")?;
+        let (_, groups) = captures.extract::<3>();
+        Ok(InputNode {
+            name: groups[0].to_string(),
+            left: groups[1].to_string(),
+            right: groups[2].to_string(),
+        })
+    }).collect::<Result<_>>()?;
+
+    Ok(Puzzle {
+        directions,
+        desert: DesertMap::from_node_vec(parsed_lines)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_REPEATED: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_GHOSTS: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[rstest]
+    #[case(EXAMPLE, 2)]
+    #[case(EXAMPLE_REPEATED, 6)]
+    fn test_example_part1(#[case] example: &str, #[case] expected: u64) {
+        let input = Day.parse(example).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), expected.into());
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = Day.parse(EXAMPLE_GHOSTS).unwrap();
+        assert_eq!(Day.part2(&input).unwrap(), 6.into());
+    }
+
+    #[test]
+    fn test_no_directions() {
+        assert!(Day.parse("\n\nAAA = (AAA, AAA)\n").is_err());
+    }
+
+    #[test]
+    fn test_end_never_reached() {
+        let input = Day.parse("L\n\n11A = (11B, 11Z)\n11B = (11B, 11B)\n11Z = (11Z, 11Z)\n").unwrap();
+        assert!(Day.part2(&input).is_err());
+    }
+}
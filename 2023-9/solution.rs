@@ -1,8 +1,8 @@
-use std::io;
 use anyhow::{Result, Ok, bail};
+use lib::solution::{Answer, Solution};
 
 #[derive(Clone)]
-struct Sequence(Vec<i64>);
+pub struct Sequence(Vec<i64>);
 
 impl Sequence {
     fn differences(&self) -> Sequence {
@@ -18,14 +18,14 @@ impl Sequence {
         let mut last_sequence = sequences.last().unwrap();
 
         loop {
-            if last_sequence.0.len() == 0 {
+            if last_sequence.0.is_empty() {
                 bail!("single nonzero difference, cannot predict the future");
             }
             if last_sequence.0.iter().all(|x| *x == 0) {
                 break;
             }
             sequences.push(last_sequence.differences());
-            last_sequence = &sequences.last().unwrap();
+            last_sequence = sequences.last().unwrap();
         }
         Ok(sequences)
     }
@@ -35,7 +35,7 @@ impl Sequence {
 
         let mut last_difference: i64 = 0;
         for sequence in sequences.iter().rev() {
-            last_difference = sequence.0.last().unwrap() + last_difference
+            last_difference += sequence.0.last().unwrap()
         }
 
         Ok(last_difference)
@@ -54,34 +54,42 @@ impl Sequence {
 }
 
 
-fn main() -> Result<()> {
-    let sequences = parse()?;
+#[derive(Default)]
+pub struct Day;
 
-    let sum_last_predictions = sequences
-        .iter()
-        .map(|x| Ok(x.predict()?))
-        .sum::<Result<i64>>()?;
+impl Solution for Day {
+    type Input = Vec<Sequence>;
 
-    println!("{}", sum_last_predictions);
+    fn parse(&self, input: &str) -> Result<Vec<Sequence>> {
+        parse(input)
+    }
+
+    fn part1(&self, sequences: &Vec<Sequence>) -> Result<Answer> {
+        let sum_last_predictions = sequences
+            .iter()
+            .map(|x| Ok(x.predict()?))
+            .sum::<Result<i64>>()?;
 
-    let sum_first_predictions = sequences
-        .iter()
-        .map(|x| Ok(x.predict_first()?))
-        .sum::<Result<i64>>()?;
+        Ok(sum_last_predictions.into())
+    }
 
-    println!("{}", sum_first_predictions);
+    fn part2(&self, sequences: &Vec<Sequence>) -> Result<Answer> {
+        let sum_first_predictions = sequences
+            .iter()
+            .map(|x| Ok(x.predict_first()?))
+            .sum::<Result<i64>>()?;
 
-    Ok(())
+        Ok(sum_first_predictions.into())
+    }
 }
 
-fn parse() -> Result<Vec<Sequence>> {
-    let stdin = io::stdin();
-    let lines = stdin.lines();
+fn parse(input: &str) -> Result<Vec<Sequence>> {
+    let lines = input.lines();
     Ok(lines
         .map(|line|
             Ok(
                 Sequence(
-                    line?.split_whitespace()
+                    line.split_whitespace()
                         .map(|num_str| Ok(num_str.parse::<i64>()?))
                         .collect::<Result<_>>()?
                 )
@@ -89,4 +97,23 @@ fn parse() -> Result<Vec<Sequence>> {
         )
         .collect::<Result<_>>()?
     )
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 114.into());
+        assert_eq!(Day.part2(&input).unwrap(), 2.into());
+    }
+}
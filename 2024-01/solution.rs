@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use anyhow::{Context, Result};
+use lib::solution::{Answer, Solution};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\d+)\s+(\d+)").unwrap());
+
+pub type Lists = [BTreeMap<i32, i32>; 2];
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Lists;
+
+    fn parse(&self, input: &str) -> Result<Lists> {
+        parse_input(input)
+    }
+
+    fn part1(&self, lists: &Lists) -> Result<Answer> {
+        let mut lists = lists.clone();
+        let mut sum = 0;
+
+        while !lists[0].is_empty() {
+            let (left, right) = lists.split_at_mut(1);
+            let mut least1 = left[0].first_entry().unwrap();
+            let mut least2 = right[0].first_entry().context("second list empty while the first list is not empty")?;
+
+            let distance = least1.key().abs_diff(*least2.key());
+
+            sum += distance;
+
+            let val1 = least1.get_mut();
+            *val1 -= 1;
+            if *val1 <= 0 {
+                least1.remove();
+            }
+
+            let val2 = least2.get_mut();
+            *val2 -= 1;
+            if *val2 <= 0 {
+                least2.remove();
+            }
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(&self, lists: &Lists) -> Result<Answer> {
+        let mut sum = 0;
+        let [left, right] = lists;
+        for (location_id, left_count) in left.iter() {
+            let right_count = right.get(location_id).unwrap_or(&0);
+            let similarity = location_id * left_count * right_count;
+            sum += similarity;
+        }
+
+        Ok(sum.into())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Lists> {
+    let mut res: Lists = Default::default();
+
+    for (i, line) in input.lines().enumerate() {
+        let captures = RE.captures(line).with_context(|| format!("No match on line {}:\n{}", i+1, line))?;
+        for (list, parsed_str) in res.iter_mut().zip([&captures[1], &captures[2]]) {
+            let parsed_int = parsed_str.parse::<i32>().with_context(|| format!("Could not parse into i32: {}", parsed_str))?;
+            list.entry(parsed_int).and_modify(|x| *x += 1).or_insert(1);
+        }
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 11.into());
+        assert_eq!(Day.part2(&input).unwrap(), 31.into());
+    }
+}
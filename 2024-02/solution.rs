@@ -0,0 +1,78 @@
+use anyhow::{Result};
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i32>>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, levels: &Vec<Vec<i32>>) -> Result<Answer> {
+        Ok(levels.iter().filter(|x| is_safe(x)).count().into())
+    }
+
+    fn part2(&self, levels: &Vec<Vec<i32>>) -> Result<Answer> {
+        Ok(levels.iter().filter(|x| is_safe_part2(x)).count().into())
+    }
+}
+
+fn is_safe(level: &[i32]) -> bool {
+    let increasing = level[1] > level[0];
+
+    is_safe_direction(level, increasing)
+}
+
+fn is_safe_part2(level: &[i32]) -> bool {
+    is_safe(level)
+    || (0..level.iter().len()).any(|i| is_safe(&skip_nth(level, i)))
+}
+
+fn is_safe_direction(level: &[i32], increasing: bool) -> bool {
+    level.windows(2).all(|window|
+        is_safe_direction_pair(window, increasing)
+    )
+}
+
+fn is_safe_direction_pair(pair: &[i32], increasing: bool) -> bool {
+    (if increasing {
+        pair[1] > pair[0]
+    } else {
+        pair[1] < pair[0]
+    }) && (pair[0].abs_diff(pair[1]) <= 3)
+}
+
+fn skip_nth(vec: &[i32], n: usize) -> Vec<i32> {
+    vec.iter().enumerate().filter(|&(i, _)| i != n).map(|(_, v)| *v).collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>> {
+    input.lines().map(|line| -> Result<Vec<i32>> {
+        line.split_whitespace().map(|x| Ok(x.parse::<i32>()?)).collect()
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 2.into());
+        assert_eq!(Day.part2(&input).unwrap(), 4.into());
+    }
+}
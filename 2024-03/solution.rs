@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
-use std::io::{stdin, Read};
 use anyhow::{Result};
+use lib::solution::{Answer, Solution};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -8,19 +8,29 @@ static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").
 const DO_STR: &str = "do()";
 const DONT_STR: &str = "don't()";
 
-fn main() -> Result<()> {
-    let mut buf = "".to_string();
-    stdin().read_to_string(&mut buf)?;
+#[derive(Default)]
+pub struct Day;
 
-    println!("{}", part2(&buf)?);
+impl Solution for Day {
+    type Input = String;
 
-    Ok(())
+    fn parse(&self, input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, memory: &String) -> Result<Answer> {
+        Ok(part1(memory)?.into())
+    }
+
+    fn part2(&self, memory: &String) -> Result<Answer> {
+        Ok(part2(memory)?.into())
+    }
 }
 
 fn part1(s: &str) -> Result<i32> {
     let mut sum = 0;
 
-    for capture in RE.captures_iter(&s) {
+    for capture in RE.captures_iter(s) {
         let mul = capture[1].parse::<i32>()? * capture[2].parse::<i32>()?;
         sum += mul;
     }
@@ -30,7 +40,7 @@ fn part1(s: &str) -> Result<i32> {
 
 fn part2(s: &str) -> Result<i32> {
     let mut sum = 0;
-    let mut capture = RE.captures(&s);
+    let mut capture = RE.captures(s);
     let mut capture_ref = capture.as_ref();
     let mut next_mul_i = capture_ref.map(|c| c.get(0).unwrap().start());
     let mut next_do_i = s.find(DO_STR);
@@ -85,4 +95,24 @@ fn cmp_idx_none_greatest((_, i): (usize, Option<usize>), (_, j): (usize, Option<
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE_PART1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const EXAMPLE_PART2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day.part1(&Day.parse(EXAMPLE_PART1).unwrap()).unwrap(), 161.into());
+        assert_eq!(Day.part2(&Day.parse(EXAMPLE_PART2).unwrap()).unwrap(), 48.into());
+    }
+}
@@ -1,14 +1,24 @@
 use anyhow::{Result};
-use std::io::stdin;
-use owned_chars::{OwnedCharsExt};
 use lib::grid::{Grid, DIRECTIONS_8, DIRECTIONS_X};
+use lib::solution::{Answer, Solution};
 
-fn main() -> Result<()> {
-    let grid = parse_input()?;
+#[derive(Default)]
+pub struct Day;
 
-    println!("{}", part2(&grid));
+impl Solution for Day {
+    type Input = Grid<char>;
 
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>) -> Result<Answer> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(&self, grid: &Grid<char>) -> Result<Answer> {
+        Ok(part2(grid).into())
+    }
 }
 
 fn part1(grid: &Grid<char>) -> i32 {
@@ -70,17 +80,32 @@ fn part2(grid: &Grid<char>) -> i32 {
     count
 }
 
-fn parse_input() -> Result<Grid<char>> {
-    Ok(itertools::process_results(
-        stdin().lines().map(
-            |line| -> Result<_> {
-                Ok(line.map(|line| {
-                    line.into_chars()
-                })?)
-            }
-        ),
-        |line| {
-            Grid::from_lines_iter(line)
-        },
-    )??)
-}
\ No newline at end of file
+fn parse_input(input: &str) -> Result<Grid<char>> {
+    Ok(Grid::from_lines_iter(input.lines().map(str::chars))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 18.into());
+        assert_eq!(Day.part2(&input).unwrap(), 9.into());
+    }
+}
@@ -1,8 +1,8 @@
 use std::collections::HashSet;
 use anyhow::{bail, Context, Result};
-use std::io::stdin;
+use lib::solution::{Answer, Solution};
 
-struct Puzzle {
+pub struct Puzzle {
     rules: Vec<Rule>,
     unordered_lists: Vec<Vec<i32>>,
 }
@@ -12,15 +12,23 @@ struct Rule {
     lesser: i32,
     greater: i32,
 }
-fn main() -> Result<()> {
-    let puzzle = parse_input()?;
+#[derive(Default)]
+pub struct Day;
 
-    //part1
-    println!("{}", order_and_sum_middle(&puzzle, true)?);
-    //part2
-    println!("{}", order_and_sum_middle(&puzzle, false)?);
+impl Solution for Day {
+    type Input = Puzzle;
 
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        Ok(order_and_sum_middle(puzzle, true)?.into())
+    }
+
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        Ok(order_and_sum_middle(puzzle, false)?.into())
+    }
 }
 
 fn order_and_sum_middle(puzzle: &Puzzle, count_ordered: bool) -> Result<i32> {
@@ -54,15 +62,14 @@ fn order_and_sum_middle(puzzle: &Puzzle, count_ordered: bool) -> Result<i32> {
     Ok(sum)
 }
 
-fn parse_input() -> Result<Puzzle> {
+fn parse_input(input: &str) -> Result<Puzzle> {
     let mut result = Puzzle{
         rules: vec![],
         unordered_lists: vec![],
     };
 
     let mut rules = true;
-    for line in stdin().lines() {
-        let line = line?;
+    for line in input.lines() {
         if rules {
             if line.is_empty() {
                 rules = false;
@@ -87,4 +94,48 @@ fn parse_input() -> Result<Puzzle> {
     }
 
     Ok(result)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 143.into());
+        assert_eq!(Day.part2(&input).unwrap(), 123.into());
+    }
+}
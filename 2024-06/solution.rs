@@ -1,9 +1,7 @@
 use std::collections::{HashSet};
-use anyhow::{Context, Result};
-use std::io::stdin;
-use std::iter::repeat;
-use owned_chars::{OwnedCharsExt};
+use anyhow::Result;
 use lib::grid::{Coord, Grid, Offset, OFFSET_DOWN, OFFSET_RIGHT, OFFSET_UP, OFFSET_LEFT};
+use lib::solution::{Answer, Solution};
 
 #[derive(Copy, Clone, Eq, Hash, PartialEq, Ord, PartialOrd)]
 struct Guard {
@@ -11,23 +9,30 @@ struct Guard {
     direction: Offset,
 }
 
-struct Puzzle {
+#[derive(Clone)]
+pub struct Puzzle {
     guard_start: Guard,
     grid: Grid<char>,
 }
 
-fn main() -> Result<()> {
-    let mut puzzle = parse_input()?;
+#[derive(Default)]
+pub struct Day;
 
-    let visited = part1(&puzzle);
+impl Solution for Day {
+    type Input = Puzzle;
 
-    println!("{}", visited.len());
-
-    let loopy_obstacles = part2(&mut puzzle, &visited)?;
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
 
-    println!("{}", loopy_obstacles);
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        Ok(part1(puzzle).len().into())
+    }
 
-    Ok(())
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        let visited = part1(puzzle);
+        Ok(part2(&mut puzzle.clone(), &visited)?.into())
+    }
 }
 
 fn part2(puzzle: &mut Puzzle, visited: &HashSet<Coord>) -> Result<i32> {
@@ -40,7 +45,7 @@ fn part2(puzzle: &mut Puzzle, visited: &HashSet<Coord>) -> Result<i32> {
         }
         *added_obstacle = '#';
 
-        if is_loop(&puzzle)? {
+        if is_loop(puzzle)? {
             loopy_obstacles += 1;
         }
 
@@ -53,7 +58,7 @@ fn part2(puzzle: &mut Puzzle, visited: &HashSet<Coord>) -> Result<i32> {
 }
 
 fn is_loop(puzzle: &Puzzle) -> Result<bool> {
-    let mut visited_states: Grid<i8> = Grid::from_lines_iter(repeat(repeat(0).take(puzzle.grid.columns())).take(puzzle.grid.rows()))?;
+    let mut visited_states: Grid<i8> = Grid::from_lines_iter(std::iter::repeat_n(std::iter::repeat_n(0, puzzle.grid.columns()), puzzle.grid.rows()))?;
     let mut guard = puzzle.guard_start;
     'outer: loop {
         for (coord, point) in puzzle.grid.iter_line(guard.position, guard.direction) {
@@ -100,33 +105,39 @@ fn offset_to_bit(offset: Offset) -> i8 {
     }
 }
 
-fn parse_input() -> Result<Puzzle> {
-    let mut guard_start = None;
-    let grid = itertools::process_results(
-        stdin().lines().map(
-            |line| -> Result<_> {
-                Ok(line.map(|line| {
-                    line.into_chars()
-                })?)
-            }
-        ),
-        |line| {
-            Grid::from_lines_iter_map(line, |coord, c| {
-                if c == '^'{
-                    guard_start = Some(Guard{
-                        position: coord,
-                        direction: OFFSET_UP,
-                    });
-                    '.'
-                } else {
-                    c
-                }
-            })
-        },
-    )??;
-
+fn parse_input(input: &str) -> Result<Puzzle> {
+    let (grid, markers) = Grid::parse_str_with_markers(input, &[('^', '.')])?;
     Ok(Puzzle{
-        guard_start: guard_start.context("position of guard not found")?,
+        guard_start: Guard{
+            position: markers[&'^'],
+            direction: OFFSET_UP,
+        },
         grid,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 41.into());
+        assert_eq!(Day.part2(&input).unwrap(), 6.into());
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{bail, Result};
+use lib::solution::{Answer, Solution};
+
+
+#[derive(Default)]
+pub struct Puzzle {
+    equations: Vec<Equation>
+}
+
+#[derive(Default)]
+struct Equation {
+    result: i64,
+    operands: Vec<i64>
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(&self, puzzle: &Puzzle) -> Result<Answer> {
+        Ok(solve(puzzle, false).into())
+    }
+
+    fn part2(&self, puzzle: &Puzzle) -> Result<Answer> {
+        Ok(solve(puzzle, true).into())
+    }
+}
+
+fn solve(puzzle: &Puzzle, with_concatenation: bool) -> i64 {
+    let mut sum = 0;
+    for equation in &puzzle.equations {
+        if result_reachable(equation.result, equation.operands[0], &equation.operands[1..], with_concatenation) {
+            sum += equation.result
+        }
+    }
+    sum
+}
+
+fn result_reachable(result: i64, accumulator: i64, operands: &[i64], with_concatenation: bool) -> bool {
+    if operands.is_empty() {
+        return result == accumulator
+    }
+    result_reachable(result, accumulator + operands[0], &operands[1..], with_concatenation)
+        || result_reachable(result, accumulator * operands[0], &operands[1..], with_concatenation)
+        // part2
+        || (with_concatenation && result_reachable(result, concatenate(accumulator, operands[0]), &operands[1..], with_concatenation))
+}
+
+fn concatenate(lhs: i64, rhs: i64) -> i64 {
+    lhs * 10_i64.pow(rhs.to_string().len() as u32) + rhs
+}
+
+fn parse_input(input: &str) -> Result<Puzzle> {
+    let mut result = Puzzle::default();
+
+    for line in input.lines() {
+        let split: Vec<&str> = line.split(": ").collect();
+        if split.len() != 2 {
+            bail!("not found exactly one ': '")
+        }
+        let operands = split[1]
+            .split(' ')
+            .map(|x| -> Result<_> {
+                Ok(x.parse::<i64>()?)
+            });
+        result.equations.push(Equation{
+            result: split[0].parse()?,
+            operands: operands.collect::<Result<_>>()?,
+        })
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 3749.into());
+        assert_eq!(Day.part2(&input).unwrap(), 11387.into());
+    }
+}
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use lib::grid::{Coord, Grid, Offset};
+use lib::solution::{Answer, Solution};
+use itertools::Either;
+use std::collections::HashMap;
+
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>) -> Result<Answer> {
+        let antennas = group_antennas(grid);
+        Ok(count_antinodes(grid, &antennas, false).into())
+    }
+
+    fn part2(&self, grid: &Grid<char>) -> Result<Answer> {
+        let antennas = group_antennas(grid);
+        Ok(count_antinodes(grid, &antennas, true).into())
+    }
+}
+
+fn count_antinodes(grid: &Grid<char>, antennas: &HashMap<char, Vec<Coord>>, resonant_harmonics: bool) -> i32 {
+    let mut antinodes = Grid::<bool>::new_sized_as(grid);
+    for antennas in antennas.values() {
+        for (i, &lhs) in antennas[..antennas.len() - 1].iter().enumerate() {
+            for &rhs in &antennas[i+1..] {
+                let offset = rhs - lhs;
+                let candidates = if resonant_harmonics {
+                    Either::Right(iter_antinodes_part2(grid, lhs, rhs, offset))
+                } else {
+                    Either::Left(iter_antinodes_part1(grid, lhs, rhs, offset))
+                };
+                for (candidate, _) in candidates
+                {
+                    antinodes[candidate] = true;
+                }
+            }
+        }
+    }
+    antinodes
+        .iter()
+        .filter(|(_, &has_antinode)| has_antinode)
+        .count() as i32
+}
+
+fn iter_antinodes_part1(grid: &Grid<char>, lhs: Coord, rhs: Coord, offset: Offset) -> impl Iterator<Item = (Coord, &char)> {
+    grid.iter_line(lhs, -offset).skip(1).take(1)
+        .chain(grid.iter_line(rhs, offset).skip(1).take(1))
+}
+
+fn iter_antinodes_part2(grid: &Grid<char>, lhs: Coord, rhs: Coord, offset: Offset) -> impl Iterator<Item = (Coord, &char)> {
+    grid.iter_line(lhs, -offset)
+        .chain(grid.iter_line(rhs, offset))
+}
+
+fn group_antennas(grid: &Grid<char>) -> HashMap<char, Vec<Coord>> {
+    let mut result: HashMap<char, Vec<Coord>> = HashMap::default();
+    for (coord, &point) in grid.iter() {
+        if point == '.' {
+            continue
+        }
+        result.entry(point).or_default().push(coord);
+    }
+
+    result
+}
+
+fn parse_input(input: &str) -> Result<Grid<char>> {
+    let grid = Grid::from_lines_iter(input.lines().map(str::chars))?;
+
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 14.into());
+        assert_eq!(Day.part2(&input).unwrap(), 34.into());
+    }
+}
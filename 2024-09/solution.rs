@@ -1,42 +1,37 @@
-use std::fmt::{Display, Formatter};
 use anyhow::{bail, Result};
-use std::io::{stdin, Read};
+use lib::solution::{Answer, Solution};
 use itertools::repeat_n;
 
-type CompressedDiskMap = Vec<u8>;
+pub type CompressedDiskMap = Vec<u8>;
 type UncompressedDiskMap = Vec<Option<u32>>;
-struct DisplayableDiskMap<'a> (&'a UncompressedDiskMap);
-
-
-impl<'a> Display for DisplayableDiskMap<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for &item in self.0 {
-            let s = item.map(|x| x.to_string());
-            let s = match &s {
-                None => ".",
-                Some(s) => s.as_str(),
-            };
-            f.write_fmt(format_args!("({s:4})"))?;
-        }
-        Ok(())
-    }
-}
 
-fn main() -> Result<()> {
-    let input = parse_input()?;
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = CompressedDiskMap;
 
-    let mut decompressed = decompress_disk_map(&input);
-    defragment_disk2(&mut decompressed);
-    let checksum = checksum(&decompressed);
-    println!("{checksum}");
+    fn parse(&self, input: &str) -> Result<CompressedDiskMap> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &CompressedDiskMap) -> Result<Answer> {
+        let mut decompressed = decompress_disk_map(input);
+        defragment_disk(&mut decompressed);
+        Ok(checksum(&decompressed).into())
+    }
 
-    Ok(())
+    fn part2(&self, input: &CompressedDiskMap) -> Result<Answer> {
+        let mut decompressed = decompress_disk_map(input);
+        defragment_disk2(&mut decompressed);
+        Ok(checksum(&decompressed).into())
+    }
 }
 
 fn decompress_disk_map(input: &CompressedDiskMap) -> UncompressedDiskMap {
     let mut is_file = true;
     let mut file_id = 0;
-    input.iter().map(|&len| {
+    input.iter().flat_map(|&len| {
         repeat_n(
             {
                 let elem = if is_file {
@@ -51,7 +46,7 @@ fn decompress_disk_map(input: &CompressedDiskMap) -> UncompressedDiskMap {
                 elem
             },
             len as usize)
-    }).flatten().collect()
+    }).collect()
 }
 
 fn defragment_disk(input: &mut UncompressedDiskMap) {
@@ -80,7 +75,7 @@ fn defragment_disk2(input: &mut UncompressedDiskMap) {
         while 0 < j && input[j].is_none() {
             j -= 1
         }
-        if 0 >= j {
+        if j == 0 {
             // reached leftmost slot and there is no more files to move
             break
         }
@@ -90,7 +85,7 @@ fn defragment_disk2(input: &mut UncompressedDiskMap) {
             file_size += 1;
             j -= 1;
         }
-        if 0 >= j {
+        if j == 0 {
             // reached leftmost slot and there is the last file, nowhere to move to
             break
         }
@@ -134,18 +129,33 @@ fn checksum(input: &UncompressedDiskMap) -> u64 {
         .sum()
 }
 
-fn parse_input() -> Result<Vec<u8>> {
-    let mut line = Vec::new();
-    stdin().read_to_end(&mut line)?;
-    Ok(line
-        .iter()
-        .map(|&x| {
-            if x >= b'0' && x <= b'9' {
+fn parse_input(input: &str) -> Result<Vec<u8>> {
+    input
+        .trim_end()
+        .bytes()
+        .map(|x| {
+            if x.is_ascii_digit() {
                 Ok(x - b'0')
             } else {
                 bail!("unexpected character")
             }
         })
-        .collect::<Result<_>>()?
-    )
-}
\ No newline at end of file
+        .collect::<Result<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 1928.into());
+        assert_eq!(Day.part2(&input).unwrap(), 2858.into());
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::{bail, Result};
+use lib::grid::{Coord, Grid, DIRECTIONS_CARDINAL};
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Grid<u8>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, grid: &Grid<u8>) -> Result<Answer> {
+        let mut sum = 0;
+        for (coord, &height) in grid.iter() {
+            if height == 0 {
+                sum += count_trail_ends_from(grid, coord);
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, grid: &Grid<u8>) -> Result<Answer> {
+        let mut sum = 0;
+        for (coord, _) in grid.iter() {
+            sum += count_trails_from(grid, coord, 0);
+        }
+        Ok(sum.into())
+    }
+}
+
+fn count_trails_from(grid: &Grid<u8>, coord: Coord, start_height: u8) -> u64 {
+    if grid[coord] != start_height {
+        return 0;
+    }
+    if grid[coord] == 9 {
+        return 1;
+    }
+    let mut sum = 0;
+    for offset in DIRECTIONS_CARDINAL {
+        if grid.is_valid(coord + offset) {
+            sum += count_trails_from(grid, coord + offset, start_height + 1)
+        }
+    }
+    sum
+}
+
+fn count_trail_ends_from(grid: &Grid<u8>, coord: Coord) -> usize {
+    let distances = grid.distance_field_by([coord], |&from, &to| to == from + 1);
+    grid.iter()
+        .filter(|&(end, &height)| height == 9 && distances[end].is_some())
+        .count()
+}
+
+fn parse_input(input: &str) -> Result<Grid<u8>> {
+    let grid = Grid::from_lines_try_iter(
+        input.lines().map(
+            |line| -> Result<_> {
+                Ok(line.bytes().map(|c| {
+                    if c.is_ascii_digit() {
+                        Ok(c - b'0')
+                    } else {
+                        bail!("non digit character")
+                    }
+                }))
+            }
+        )
+    )?;
+
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 36.into());
+        assert_eq!(Day.part2(&input).unwrap(), 81.into());
+    }
+}
@@ -1,23 +1,36 @@
 use anyhow::Result;
+use lib::solution::{Answer, Solution};
 use std::collections::HashMap;
-use std::io::{stdin, Read};
 
-type StoneEngraving = u64;
+pub type StoneEngraving = u64;
 
 type CompactStoneLine = HashMap<StoneEngraving, u64>;
 
-fn main() -> Result<()> {
-    let input = parse_input()?;
-    let mut input_line = vec_to_stone_line(&input);
+#[derive(Default)]
+pub struct Day;
 
-    for i in 0..75 {
-        blink_line(&mut input_line);
-        println!("blink {i} results in {} stones", input_line.values().sum::<u64>());
+impl Solution for Day {
+    type Input = Vec<StoneEngraving>;
+
+    fn parse(&self, input: &str) -> Result<Vec<StoneEngraving>> {
+        parse_input(input)
     }
 
-    println!("{}", input_line.values().sum::<u64>());
+    fn part1(&self, stones: &Vec<StoneEngraving>) -> Result<Answer> {
+        let mut stones = stones.clone();
+        for _ in 0..25 {
+            blink(&mut stones);
+        }
+        Ok(stones.len().into())
+    }
 
-    Ok(())
+    fn part2(&self, stones: &Vec<StoneEngraving>) -> Result<Answer> {
+        let mut stone_line = vec_to_stone_line(stones);
+        for _ in 0..75 {
+            blink_line(&mut stone_line);
+        }
+        Ok(stone_line.values().sum::<u64>().into())
+    }
 }
 
 fn vec_to_stone_line(stones: &Vec<StoneEngraving>) -> CompactStoneLine {
@@ -36,7 +49,7 @@ fn blink(input: &mut Vec<StoneEngraving>) {
             0 => {
                 next.push(1);
             }
-            x if number_of_digits(x) % 2 == 0 => {
+            x if number_of_digits(x).is_multiple_of(2) => {
                 let (a, b) = split_stone(x);
                 next.push(a);
                 next.push(b);
@@ -58,7 +71,7 @@ fn blink_line(input: &mut CompactStoneLine) {
             0 => {
                 *next.entry(1).or_default() += count;
             }
-            x if number_of_digits(x) % 2 == 0 => {
+            x if number_of_digits(x).is_multiple_of(2) => {
                 let (a, b) = split_stone(x);
                 *next.entry(a).or_default() += count;
                 *next.entry(b).or_default() += count;
@@ -85,13 +98,26 @@ fn split_stone(n: StoneEngraving) -> (StoneEngraving, StoneEngraving) {
     )
 }
 
-fn parse_input() -> Result<Vec<StoneEngraving>> {
-    let mut buf = Default::default();
-
-    stdin().read_to_string(&mut buf)?;
-
-    Ok(buf
+fn parse_input(input: &str) -> Result<Vec<StoneEngraving>> {
+    input
         .split_ascii_whitespace()
         .map(|x| Ok(x.parse()?))
-        .collect::<Result<Vec<_>>>()?)
+        .collect::<Result<Vec<_>>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+125 17
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 55312.into());
+        assert_eq!(Day.part2(&input).unwrap(), 65601038650482u64.into());
+    }
 }
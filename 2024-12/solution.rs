@@ -0,0 +1,98 @@
+use anyhow::Result;
+use lib::grid::{Connectivity, Grid};
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>) -> Result<Answer> {
+        let regions = grid.regions(Connectivity::Four, |a, b| a == b);
+        Ok(regions.iter().map(|region| (region.perimeter() * region.area()) as u64).sum::<u64>().into())
+    }
+
+    fn part2(&self, grid: &Grid<char>) -> Result<Answer> {
+        let regions = grid.regions(Connectivity::Four, |a, b| a == b);
+        Ok(regions.iter().map(|region| (region.sides() * region.area()) as u64).sum::<u64>().into())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<char>> {
+    Ok(Grid::from_lines_iter(input.lines().map(str::chars))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    const EXAMPLE_SMALL: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const EXAMPLE_ENCLOSED: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+    const EXAMPLE_LARGE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const EXAMPLE_E: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const EXAMPLE_DIAGONAL: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[rstest]
+    #[case(EXAMPLE_SMALL, 140)]
+    #[case(EXAMPLE_ENCLOSED, 772)]
+    #[case(EXAMPLE_LARGE, 1930)]
+    fn test_part1(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(Day.part1(&Day.parse(input).unwrap()).unwrap(), expected.into());
+    }
+
+    #[rstest]
+    #[case(EXAMPLE_SMALL, 80)]
+    #[case(EXAMPLE_ENCLOSED, 436)]
+    #[case(EXAMPLE_E, 236)]
+    #[case(EXAMPLE_DIAGONAL, 368)]
+    #[case(EXAMPLE_LARGE, 1206)]
+    fn test_part2(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(Day.part2(&Day.parse(input).unwrap()).unwrap(), expected.into());
+    }
+}
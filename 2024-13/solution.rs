@@ -1,12 +1,12 @@
 use anyhow::{Context, Result};
 use lib::grid::Coord;
+use lib::solution::{Answer, Solution};
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::io::stdin;
 
-#[derive(Debug)]
-struct Machine {
+#[derive(Clone, Debug)]
+pub struct Machine {
     button_a: Coord,
     button_b: Coord,
     prize: Coord,
@@ -21,26 +21,39 @@ struct WinCombination {
 static RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?:Button A|Button B|Prize): X[+=](\d+), Y[+=](\d+)").unwrap());
 
-fn main() -> Result<()> {
-    let mut input = parse_input()?;
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Machine>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, machines: &Vec<Machine>) -> Result<Answer> {
+        Ok(fewest_tokens(machines, Some(100)).into())
+    }
 
-    for m in &mut input {
-        part2_conversion(m);
+    fn part2(&self, machines: &Vec<Machine>) -> Result<Answer> {
+        let mut machines = machines.clone();
+        for m in &mut machines {
+            part2_conversion(m);
+        }
+        Ok(fewest_tokens(&machines, None).into())
     }
+}
 
-    println!(
-        "{}",
-        input
+fn fewest_tokens(machines: &[Machine], max_presses: Option<i64>) -> u64 {
+    machines
+        .iter()
+        .map(|m| win_combinations(m)
             .iter()
-            .map(|m| win_combinations(m)
-                .iter()
-                .map(combination_price)
-                .min()
-                .unwrap_or_default())
-            .sum::<u64>()
-    );
-
-    Ok(())
+            .filter(|c| max_presses.is_none_or(|max| c.button_a <= max && c.button_b <= max))
+            .map(combination_price)
+            .min()
+            .unwrap_or_default())
+        .sum::<u64>()
 }
 
 fn win_combinations(machine: &Machine) -> Vec<WinCombination> {
@@ -86,27 +99,58 @@ fn part2_conversion(machine: &mut Machine) {
     machine.prize.y += CONV;
 }
 
-fn parse_input() -> Result<Vec<Machine>> {
-    stdin()
+fn parse_input(input: &str) -> Result<Vec<Machine>> {
+    input
         .lines()
         .chunks(4)
         .into_iter()
         .map(|mut chunk| -> Result<Machine> {
             Ok(Machine {
-                button_a: parse_to_coord(chunk.next().context("expected button A line")??)?,
-                button_b: parse_to_coord(chunk.next().context("expected button B line")??)?,
-                prize: parse_to_coord(chunk.next().context("expected prize line")??)?,
+                button_a: parse_to_coord(chunk.next().context("expected button A line")?)?,
+                button_b: parse_to_coord(chunk.next().context("expected button B line")?)?,
+                prize: parse_to_coord(chunk.next().context("expected prize line")?)?,
             })
         })
         .collect()
 }
 
-fn parse_to_coord(line: String) -> Result<Coord> {
+fn parse_to_coord(line: &str) -> Result<Coord> {
     let cap = RE
-        .captures(line.as_str())
+        .captures(line)
         .context("X and Y coordinates found")?;
     Ok(Coord {
         x: cap[1].parse()?,
         y: cap[2].parse()?,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 480.into());
+        assert_eq!(Day.part2(&input).unwrap(), 875318608908u64.into());
+    }
+}
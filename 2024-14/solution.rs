@@ -0,0 +1,143 @@
+use anyhow::{bail, Context, Result};
+use lib::geometry::Rect;
+use lib::grid::{Coord, Offset};
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)").unwrap());
+
+#[derive(Clone)]
+pub struct Robot {
+    position: Coord,
+    velocity: Offset,
+}
+
+// size of the area the robots move in
+pub struct Day {
+    rows: usize,
+    columns: usize,
+}
+
+impl Default for Day {
+    fn default() -> Self {
+        Day {
+            rows: 103,
+            columns: 101,
+        }
+    }
+}
+
+impl Solution for Day {
+    type Input = Vec<Robot>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Robot>> {
+        if self.rows == 0 || self.columns == 0 {
+            bail!("the area needs at least one row and column, got {}x{}", self.columns, self.rows);
+        }
+        parse_input(input)
+    }
+
+    fn part1(&self, robots: &Vec<Robot>) -> Result<Answer> {
+        let mut robots = robots.clone();
+        for robot in &mut robots {
+            self.run_for(robot, 100)?
+        }
+        Ok(self.count_quadrants(&robots).into())
+    }
+
+    fn part2(&self, robots: &Vec<Robot>) -> Result<Answer> {
+        // robots drawing the tree are clustered in one quadrant, giving an unusually low safety factor.
+        // Positions repeat after rows * columns seconds.
+        let mut robots = robots.clone();
+        let mut min_factor = (self.count_quadrants(&robots), 0);
+        for i in 1..self.rows * self.columns {
+            for robot in &mut robots {
+                self.run_for(robot, 1)?
+            }
+            min_factor = min_factor.min((self.count_quadrants(&robots), i));
+        }
+        Ok(min_factor.1.into())
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new().with("rows", &mut self.rows).with("columns", &mut self.columns)
+    }
+}
+
+impl Day {
+    fn run_for(&self, robot: &mut Robot, time: i64) -> Result<()> {
+        robot.position = Coord {
+            x: robot.position.x + time * robot.velocity.x,
+            y: robot.position.y + time * robot.velocity.y,
+        }
+        .wrapped(self.columns, self.rows)
+        .context("area too large to wrap into")?;
+        Ok(())
+    }
+
+    fn count_quadrants(&self, robots: &[Robot]) -> i64 {
+        let Some(quadrants) = Rect::from_size(self.columns, self.rows).and_then(|area| area.quadrants()) else {
+            return 0;
+        };
+        quadrants
+            .iter()
+            .map(|quadrant| robots.iter().filter(|robot| quadrant.contains(robot.position)).count() as i64)
+            .product()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>> {
+    input
+        .lines()
+        .map(|line| -> Result<Robot> {
+            let cap = RE
+                .captures(line)
+                .context("invalid robot state in line")?;
+            Ok(Robot {
+                position: Coord{
+                    x: cap[1].parse()?,
+                    y: cap[2].parse()?,
+                },
+                velocity: Offset{
+                    x: cap[3].parse()?,
+                    y: cap[4].parse()?,
+                },
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn test_example_part1() {
+        let day = Day { rows: 7, columns: 11 };
+        assert_eq!(day.part1(&day.parse(EXAMPLE).unwrap()).unwrap(), 12.into());
+    }
+
+    #[test]
+    fn test_empty_area() {
+        assert!(Day { rows: 0, columns: 11 }.parse(EXAMPLE).is_err());
+    }
+}
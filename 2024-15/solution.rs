@@ -1,9 +1,10 @@
-use anyhow::{bail, Context, Result};
+use anyhow::{Context, Result};
 use lib::grid::{Coord, Grid, Offset, OFFSET_DOWN, OFFSET_LEFT, OFFSET_RIGHT, OFFSET_UP};
-use owned_chars::OwnedCharsExt;
-use std::io::{stdin, BufRead, Read};
+use lib::grid_tile;
+use lib::solution::{Answer, Solution};
 
-struct Puzzle {
+#[derive(Clone)]
+pub struct Puzzle {
     grid: Grid<Tile>,
     position: Coord,
     move_plan: Vec<Offset>,
@@ -18,54 +19,73 @@ enum Tile {
     Empty,
 }
 
-fn main() -> Result<()> {
-    let mut input = parse_input()?;
-    let mut bigger_input = make_it_bigger(&input);
-
-    for &offset in &input.move_plan {
-        // part 1
-        let next = input.position + offset;
-        match input.grid[next] {
-            Tile::Wall => {}
-            Tile::Box => {
-                if move_box(&mut input.grid, next, offset) {
+grid_tile!(Tile {
+    '#' => Tile::Wall,
+    'O' => Tile::Box,
+    '.' => Tile::Empty,
+});
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Puzzle) -> Result<Answer> {
+        let mut input = input.clone();
+
+        for &offset in &input.move_plan {
+            let next = input.position + offset;
+            match input.grid[next] {
+                Tile::Wall => {}
+                Tile::Box => {
+                    if move_box(&mut input.grid, next, offset) {
+                        input.position = next;
+                    }
+                }
+                Tile::Empty => {
                     input.position = next;
                 }
             }
-            Tile::Empty => {
-                input.position = next;
-            }
         }
 
-        // part 2
-        let next = bigger_input.position + offset;
-        match bigger_input.grid[next] {
-            Tile::Wall => {}
-            Tile::Box => {
-                if move_box2(&mut bigger_input.grid, next, offset, false) {
-                    move_box2(&mut bigger_input.grid, next, offset, true);
-                    bigger_input.position = next;
+        Ok(calc_grid_gps_sum(&input.grid).into())
+    }
+
+    fn part2(&self, input: &Puzzle) -> Result<Answer> {
+        let mut bigger_input = make_it_bigger(input);
+
+        for &offset in &input.move_plan {
+            let next = bigger_input.position + offset;
+            match bigger_input.grid[next] {
+                Tile::Wall => {}
+                Tile::Box => {
+                    if move_box2(&mut bigger_input.grid, next, offset, false) {
+                        move_box2(&mut bigger_input.grid, next, offset, true);
+                        bigger_input.position = next;
+                    }
                 }
-            }
-            Tile::Empty => {
-                // check if robot hits right side of box
-                let next_left = next + OFFSET_LEFT;
-                if matches!(bigger_input.grid[next_left], Tile::Box) {
-                    if move_box2(&mut bigger_input.grid, next_left, offset, false) {
-                        move_box2(&mut bigger_input.grid, next_left, offset, true);
+                Tile::Empty => {
+                    // check if robot hits right side of box
+                    let next_left = next + OFFSET_LEFT;
+                    if matches!(bigger_input.grid[next_left], Tile::Box) {
+                        if move_box2(&mut bigger_input.grid, next_left, offset, false) {
+                            move_box2(&mut bigger_input.grid, next_left, offset, true);
+                            bigger_input.position = next;
+                        }
+                    } else {
                         bigger_input.position = next;
                     }
-                } else {
-                    bigger_input.position = next;
                 }
             }
         }
-    }
-
-    println!("{}", calc_grid_gps_sum(&input.grid));
-    println!("{}", calc_grid_gps_sum(&bigger_input.grid));
 
-    Ok(())
+        Ok(calc_grid_gps_sum(&bigger_input.grid).into())
+    }
 }
 
 fn move_box(grid: &mut Grid<Tile>, coord: Coord, offset: Offset) -> bool {
@@ -160,37 +180,11 @@ fn make_it_bigger(puzzle: &Puzzle) -> Puzzle {
     new_puzzle
 }
 
-fn parse_input() -> Result<Puzzle> {
-    let mut position: Option<Coord> = None;
-    let mut stdin = stdin().lock();
-    let grid = Grid::from_lines_try_iter_map(
-        (&mut stdin)
-            .lines()
-            .take_while(|line| !matches!(line, Ok(line) if line.is_empty()))
-            .map(|line| -> Result<_> {
-                let line = line?;
-                Ok(line.into_chars())
-            }),
-        |coord, c| {
-            Ok(match c {
-                '#' => Tile::Wall,
-                'O' => Tile::Box,
-                '.' => Tile::Empty,
-                '@' => {
-                    if position.is_some() {
-                        bail!("found duplicate robot position")
-                    }
-                    position = Some(coord);
-                    Tile::Empty
-                }
-                _ => bail!("unexpected character for map"),
-            })
-        },
-    )?;
+fn parse_input(input: &str) -> Result<Puzzle> {
+    let (map, moves) = input.split_once("\n\n").context("no empty line after the map")?;
+    let (grid, markers) = Grid::parse_str_with_markers(map, &[('@', '.')])?;
 
-    let mut buf = String::new();
-    stdin.read_to_string(&mut buf)?;
-    let move_plan = buf
+    let move_plan = moves
         .chars()
         .filter_map(|c| match c {
             '>' => Some(OFFSET_RIGHT),
@@ -203,7 +197,58 @@ fn parse_input() -> Result<Puzzle> {
 
     Ok(Puzzle {
         grid,
-        position: position.context("robot position not found")?,
+        position: markers[&'@'],
         move_plan,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE_SMALL: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const EXAMPLE_LARGE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day.part1(&Day.parse(EXAMPLE_SMALL).unwrap()).unwrap(), 2028.into());
+        let input = Day.parse(EXAMPLE_LARGE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 10092.into());
+        assert_eq!(Day.part2(&input).unwrap(), 9021.into());
+    }
+}
@@ -0,0 +1,124 @@
+use anyhow::{Context, Result};
+use lib::grid::{Coord, Grid, Offset, OFFSET_RIGHT};
+use lib::grid_tile;
+use lib::search::{dijkstra_all, ShortestPaths};
+use lib::solution::{Answer, Solution};
+
+pub struct Puzzle {
+    grid: Grid<Tile>,
+    start: Coord,
+    end: Coord,
+}
+
+#[derive(Default, Clone, Copy)]
+enum Tile {
+    Wall,
+    #[default]
+    Empty,
+}
+
+grid_tile!(Tile {
+    '#' => Tile::Wall,
+    '.' => Tile::Empty,
+});
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Puzzle) -> Result<Answer> {
+        Ok(best_paths(input)?.cost.into())
+    }
+
+    fn part2(&self, input: &Puzzle) -> Result<Answer> {
+        Ok(best_paths(input)?.union(|&(coord, _)| coord).len().into())
+    }
+}
+
+fn best_paths(input: &Puzzle) -> Result<ShortestPaths<(Coord, Offset), u64>> {
+    dijkstra_all(
+        [(input.start, OFFSET_RIGHT)],
+        |&(coord, offset)| {
+            let mut next = vec![
+                ((coord, offset.rotate_left()), 1000),
+                ((coord, offset.rotate_right()), 1000),
+            ];
+            let forward = coord + offset;
+            if matches!(input.grid.get(forward), Some(Tile::Empty)) {
+                next.push(((forward, offset), 1));
+            }
+            next
+        },
+        |&(coord, _)| coord == input.end,
+    )
+    .context("end tile not reachable")
+}
+
+fn parse_input(input: &str) -> Result<Puzzle> {
+    let (grid, markers) = Grid::parse_str_with_markers(input, &[('S', '.'), ('E', '.')])?;
+    Ok(Puzzle {
+        grid,
+        start: markers[&'S'],
+        end: markers[&'E'],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    const EXAMPLE_FIRST: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const EXAMPLE_SECOND: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[rstest]
+    #[case(EXAMPLE_FIRST, 7036, 45)]
+    #[case(EXAMPLE_SECOND, 11048, 64)]
+    fn test_example(#[case] input: &str, #[case] part1: u64, #[case] part2: u64) {
+        let input = Day.parse(input).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), part1.into());
+        assert_eq!(Day.part2(&input).unwrap(), part2.into());
+    }
+}
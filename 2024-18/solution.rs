@@ -0,0 +1,136 @@
+use anyhow::{bail, Context, Result};
+use lib::grid::{Coord, Grid};
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
+
+pub type Tile = Option<u64>;
+
+pub struct Day {
+    size: usize,
+    // bytes fallen before part 1 walks
+    bytes: u64,
+}
+
+impl Default for Day {
+    fn default() -> Self {
+        Day { size: 71, bytes: 1024 }
+    }
+}
+
+impl Solution for Day {
+    type Input = Grid<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Grid<Tile>> {
+        if self.size == 0 {
+            bail!("the memory space needs a size of at least 1");
+        }
+        if self.bytes == 0 {
+            bail!("part 1 needs at least 1 fallen byte");
+        }
+        parse_input(input, self.size)
+    }
+
+    fn part1(&self, grid: &Grid<Tile>) -> Result<Answer> {
+        let cost_to_end = shortest_path_to_end(grid, self.bytes - 1);
+        Ok(cost_to_end.context("no path to end")?.into())
+    }
+
+    fn part2(&self, grid: &Grid<Tile>) -> Result<Answer> {
+        let fallen = grid.iter().filter_map(|(_, &t)| t).max().context("no bytes fall")?;
+        let times = Vec::from_iter(self.bytes..=fallen);
+        let i = times.partition_point(|&time| {
+            shortest_path_to_end(grid, time).is_some()
+        });
+        let time = *times.get(i).context("path never blocked")?;
+        let pos = grid
+            .iter()
+            .find(|(_, &t)| matches!(t, Some(x) if x == time))
+            .context(format!("no wall at time {} found", time))?
+            .0;
+        Ok(format!("{},{}", pos.x, pos.y).into())
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new().with("size", &mut self.size).with("bytes", &mut self.bytes)
+    }
+}
+
+fn shortest_path_to_end(grid: &Grid<Tile>, time: u64) -> Option<u64> {
+    let end = Coord {
+        x: grid.columns() as i64 - 1,
+        y: grid.rows() as i64 - 1,
+    };
+
+    let distances = grid.distance_field([Coord { x: 0, y: 0 }], |tile| {
+        !matches!(tile, Some(wall_at_time) if *wall_at_time <= time)
+    });
+
+    distances[end].map(u64::from)
+}
+
+fn parse_input(input: &str, size: usize) -> Result<Grid<Tile>> {
+    let mut grid: Grid<Tile> = Grid::new(size, size);
+    for (i, line) in input.lines().enumerate() {
+        let (x, y) = line.split_once(',').with_context(|| format!("expected x,y, got '{line}'"))?;
+        let coord = Coord {
+            x: x.parse().with_context(|| format!("parsing x of '{line}'"))?,
+            y: y.parse().with_context(|| format!("parsing y of '{line}'"))?,
+        };
+        let tile = grid.get_mut(coord).with_context(|| format!("byte at {},{} is outside the memory space", coord.x, coord.y))?;
+        if tile.is_none() {
+            *tile = Some(i as u64)
+        }
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn test_example() {
+        let day = Day { size: 7, bytes: 12 };
+        let input = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&input).unwrap(), 22.into());
+        assert_eq!(day.part2(&input).unwrap(), "6,1".into());
+    }
+
+    #[rstest]
+    #[case(Day { size: 0, bytes: 12 })]
+    #[case(Day { size: 7, bytes: 0 })]
+    #[case(Day { size: 6, bytes: 12 })]
+    fn test_invalid_params(#[case] day: Day) {
+        assert!(day.parse(EXAMPLE).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use lib::solution::{Answer, Solution};
+use std::cmp::min;
+use std::collections::HashMap;
+
+type Pattern = Vec<char>;
+
+pub struct Puzzle {
+    available: Vec<Pattern>,
+    target: Vec<Pattern>,
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Puzzle) -> Result<Answer> {
+        let count = input
+            .target
+            .iter()
+            .filter(|x| is_possible(x, &input.available))
+            .count();
+
+        Ok(count.into())
+    }
+
+    fn part2(&self, input: &Puzzle) -> Result<Answer> {
+        let mut cache: HashMap<Pattern, u64> = Default::default();
+        let count = input
+            .target
+            .iter()
+            .map(|x| possible_ways(x, &input.available, &mut cache))
+            .sum::<u64>();
+
+        Ok(count.into())
+    }
+}
+
+fn is_possible(target: &[char], available: &Vec<Pattern>) -> bool {
+    if target.is_empty() {
+        return true;
+    }
+    available.iter().any(|x| {
+        target.starts_with(x) && is_possible(&target[min(target.len(), x.len())..], available)
+    })
+}
+
+fn possible_ways(
+    target: &[char],
+    available: &Vec<Pattern>,
+    cache: &mut HashMap<Pattern, u64>,
+) -> u64 {
+    if let Some(&cached) = cache.get(target) {
+        return cached;
+    }
+    if target.is_empty() {
+        return 1;
+    }
+    let ways = available
+        .iter()
+        .map(|x| {
+            if target.starts_with(x) {
+                possible_ways(&target[min(target.len(), x.len())..], available, cache)
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    cache.insert(target.into(), ways);
+    ways
+}
+
+fn parse_input(input: &str) -> Result<Puzzle> {
+    let mut lines = input.lines();
+    let available = lines
+        .next()
+        .context("expected line of available patterns")?
+        .split(", ")
+        .map(|s| s.chars().collect_vec())
+        .collect_vec();
+    lines.next();
+    let target = lines
+        .map(|x| x.chars().collect_vec())
+        .collect_vec();
+
+    Ok(Puzzle { available, target })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 6.into());
+        assert_eq!(Day.part2(&input).unwrap(), 16.into());
+    }
+}
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use lib::grid::{Coord, Grid};
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
+
+pub struct Puzzle {
+    grid: Grid<char>,
+    start: Coord,
+}
+
+pub struct Day {
+    // picoseconds a cheat has to save to be counted
+    min_saved: u64,
+}
+
+impl Default for Day {
+    fn default() -> Self {
+        Day { min_saved: 100 }
+    }
+}
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Puzzle) -> Result<Answer> {
+        Ok(count_cheats(input, 2, self.min_saved).into())
+    }
+
+    fn part2(&self, input: &Puzzle) -> Result<Answer> {
+        Ok(count_cheats(input, 20, self.min_saved).into())
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new().with("min_saved", &mut self.min_saved)
+    }
+}
+
+fn count_cheats(input: &Puzzle, max_cheat: u64, min_saved: u64) -> usize {
+    let distances = input.grid.distance_field([input.start], |&c| c == '.');
+
+    input
+        .grid
+        .pairs_within_manhattan(max_cheat)
+        .filter_map(|(from, to, cheat_dist)| {
+            let (dist, dist_to) = (distances[from]? as u64, distances[to]? as u64);
+            (dist_to > dist + cheat_dist).then(|| dist_to - dist - cheat_dist)
+        })
+        .filter(|&x| x >= min_saved)
+        .count()
+}
+
+fn parse_input(input: &str) -> Result<Puzzle> {
+    let (grid, markers) = Grid::parse_str_with_markers(input, &[('S', '.'), ('E', '.')])?;
+    Ok(Puzzle {
+        grid,
+        start: markers[&'S'],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[rstest]
+    #[case(20, 5)]
+    #[case(64, 1)]
+    fn test_example_part1(#[case] min_saved: u64, #[case] expected: u64) {
+        let day = Day { min_saved };
+        assert_eq!(day.part1(&day.parse(EXAMPLE).unwrap()).unwrap(), expected.into());
+    }
+
+    #[rstest]
+    #[case(50, 285)]
+    #[case(76, 3)]
+    fn test_example_part2(#[case] min_saved: u64, #[case] expected: u64) {
+        let day = Day { min_saved };
+        assert_eq!(day.part2(&day.parse(EXAMPLE).unwrap()).unwrap(), expected.into());
+    }
+}
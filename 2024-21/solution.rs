@@ -1,10 +1,11 @@
 use anyhow::Result;
 use lib::grid::{Coord, Grid, Offset, OFFSET_DOWN, OFFSET_LEFT, OFFSET_RIGHT, OFFSET_UP};
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::io::stdin;
 
-type Code = Vec<u8>;
+pub type Code = Vec<u8>;
 
 struct Keyboard {
     grid: Grid<u8>,
@@ -26,24 +27,58 @@ impl From<Grid<u8>> for Keyboard {
     }
 }
 
-const KEYBOARD_COUNT: u64 = 26;
+pub struct Day {
+    // robots' directional keypads between the door keypad and the one I type on
+    robots: u64,
+    robots_part2: u64,
+}
+
+impl Default for Day {
+    fn default() -> Self {
+        Day {
+            robots: 2,
+            robots_part2: 25,
+        }
+    }
+}
 
-fn main() -> Result<()> {
-    let input = parse_input()?;
+impl Solution for Day {
+    type Input = Vec<Code>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Code>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, codes: &Vec<Code>) -> Result<Answer> {
+        // robots' directional keypads and the one I type on
+        Ok(complexity_sum(codes, self.robots + 1)?.into())
+    }
 
+    fn part2(&self, codes: &Vec<Code>) -> Result<Answer> {
+        Ok(complexity_sum(codes, self.robots_part2 + 1)?.into())
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new()
+            .with("robots", &mut self.robots)
+            .with("robots_part2", &mut self.robots_part2)
+    }
+}
+
+fn complexity_sum(codes: &[Code], keyboard_count: u64) -> Result<u64> {
     let final_keypad: Keyboard = Grid::from_lines_iter(
-        vec!["789", "456", "123", ".0A"]
+        ["789", "456", "123", ".0A"]
             .iter()
             .map(|x| x.bytes().clone()),
     )?
     .into();
     let intermediate_keypad: Keyboard =
-        Grid::from_lines_iter(vec![".^A", "<v>"].iter().map(|x| x.bytes().clone()))?.into();
+        Grid::from_lines_iter([".^A", "<v>"].iter().map(|x| x.bytes().clone()))?.into();
 
     let mut cache: HashMap<Code, HashMap<u64, u64>> = Default::default();
 
     let mut sum = 0;
-    for code in &input {
+    for code in codes {
         let mut start_coord = final_keypad.coords[&b'A'];
         let mut len = 0;
         for &symbol in code {
@@ -64,26 +99,23 @@ fn main() -> Result<()> {
             start_coord = start_coord + offset;
             len += candidate_codes
                 .iter()
-                .map(|code| get_code_price(code, KEYBOARD_COUNT, &intermediate_keypad, &mut cache))
+                .map(|code| get_code_price(code, keyboard_count, &intermediate_keypad, &mut cache))
                 .min()
                 .unwrap()
         }
 
         let complexity = complexity(code, len)?;
         sum += complexity;
-        println!("{complexity} {}", len);
-        println!("{}", String::from_utf8(code.clone())?,);
     }
-    println!("{sum}");
 
-    Ok(())
+    Ok(sum)
 }
 
 fn get_code_price(
     code: &Vec<u8>,
     level: u64,
     keyboard: &Keyboard,
-    mut cache: &mut HashMap<Code, HashMap<u64, u64>>,
+    cache: &mut HashMap<Code, HashMap<u64, u64>>,
 ) -> u64 {
     if let Some(cached) = cache.get(code) {
         if let Some(&cached_level) = cached.get(&level) {
@@ -114,7 +146,7 @@ fn get_code_price(
             start_coord = start_coord + offset;
             len += candidate_codes
                 .iter()
-                .map(|code| get_code_price(code, level - 1, &keyboard, &mut cache))
+                .map(|code| get_code_price(code, level - 1, keyboard, cache))
                 .min()
                 .unwrap()
         }
@@ -151,7 +183,7 @@ fn get_paths(offset: Offset) -> Vec<Vec<Offset>> {
     }
 }
 
-fn symbols_with_activate(path: &Vec<Offset>) -> Vec<u8> {
+fn symbols_with_activate(path: &[Offset]) -> Vec<u8> {
     let mut res = path.iter().cloned().map(offset_to_symbol).collect_vec();
     res.push(b'A');
     res
@@ -179,9 +211,31 @@ fn complexity(code: &Code, code_len: u64) -> Result<u64> {
         .parse::<u64>()?)
 }
 
-fn parse_input() -> Result<Vec<Code>> {
-    Ok(stdin()
+fn parse_input(input: &str) -> Result<Vec<Code>> {
+    input
         .lines()
-        .map(|line| -> Result<_> { Ok(line?.as_bytes().to_owned()) })
-        .try_collect()?)
+        .map(|line| -> Result<_> { Ok(line.as_bytes().to_owned()) })
+        .try_collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn test_example() {
+        let day = Day::default();
+        let input = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&input).unwrap(), 126384.into());
+        assert_eq!(day.part2(&input).unwrap(), 154115708116294u64.into());
+    }
 }
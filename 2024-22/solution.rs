@@ -0,0 +1,113 @@
+use anyhow::Result;
+use lib::solution::{Answer, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<u64>) -> Result<Answer> {
+        let mut sum = 0;
+        for &init in input {
+            sum += evolve(init, 2000);
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, input: &Vec<u64>) -> Result<Answer> {
+        let mut seq_prices = vec![];
+        for &init in input {
+            seq_prices.push(run_sequence_output(init, 2000));
+        }
+
+        let mut max = 0;
+        for t in itertools::iproduct!(-9..=9, -9..=9, -9..=9, -9..=9) {
+            let sequence = vec![t.0, t.1, t.2, t.3];
+            let mut sum = 0;
+            for input_prices in &seq_prices {
+                sum += input_prices.get(&sequence).cloned().unwrap_or_default() as u64;
+            }
+            if max < sum {
+                max = sum
+            }
+        }
+
+        Ok(max.into())
+    }
+}
+
+const MOD: u64 = 16777216;
+
+fn evolve(init: u64, iterations: u64) -> u64 {
+    let mut v = init;
+    for _ in 0..iterations {
+        v ^= (v * 64) % MOD;
+        v ^= (v / 32) % MOD;
+        v ^= (v * 2048) % MOD;
+    }
+    v
+}
+
+type Sequence = Vec<i8>;
+
+fn run_sequence_output(init: u64, iterations: u64) -> HashMap<Sequence, i8> {
+    let mut v = init;
+    let mut res: HashMap<Sequence, i8> = Default::default();
+    let mut seq = vec![];
+    for _ in 0..iterations {
+        let prev_price = (v % 10) as i8;
+        v ^= (v * 64) % MOD;
+        v ^= (v / 32) % MOD;
+        v ^= (v * 2048) % MOD;
+        let price = (v % 10) as i8;
+        let change = price - prev_price;
+        if seq.len() == 4 {
+            seq.remove(0);
+        }
+        seq.push(change);
+        if seq.len() == 4 {
+            res.entry(seq.clone()).or_insert(price);
+        }
+    }
+    res
+}
+
+fn parse_input(input: &str) -> Result<Vec<u64>> {
+    input
+        .lines()
+        .map(|line| -> Result<_> { Ok(line.parse::<u64>()?) })
+        .try_collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE_PART1: &str = "\
+1
+10
+100
+2024
+";
+
+    const EXAMPLE_PART2: &str = "\
+1
+2
+3
+2024
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day.part1(&Day.parse(EXAMPLE_PART1).unwrap()).unwrap(), 37327623.into());
+        assert_eq!(Day.part2(&Day.parse(EXAMPLE_PART2).unwrap()).unwrap(), 23.into());
+    }
+}
@@ -0,0 +1,139 @@
+use anyhow::{Context, Result};
+use lib::solution::{Answer, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+pub type Node = String;
+pub type Connection = [Node; 2];
+type Clique = Vec<Node>;
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Connection>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Connection>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<Connection>) -> Result<Answer> {
+        let cliques = build_cliques(input, Some(3));
+
+        let count = cliques[&3].iter().filter(|&x| {
+            for node in x {
+                if node.starts_with("t") {
+                    return true
+                }
+            }
+            false
+        }).count();
+
+        Ok(count.into())
+    }
+
+    fn part2(&self, input: &Vec<Connection>) -> Result<Answer> {
+        let cliques = build_cliques(input, None);
+
+        let max_k = cliques.keys().filter(|k| !cliques[k].is_empty()).max().context("no connections")?;
+        let max_clique = cliques[max_k].iter().next().unwrap();
+
+        Ok(max_clique.join(",").into())
+    }
+}
+
+// all cliques by their size, up to max_k or up to the largest one
+fn build_cliques(input: &[Connection], max_k: Option<u64>) -> HashMap<u64, HashSet<Clique>> {
+    let mut adjecency: HashMap<Node, HashSet<Node>> = Default::default();
+
+    let mut cliques2: HashSet<Clique> = Default::default();
+    for connection in input {
+        adjecency.entry(connection[0].clone()).or_default().insert(connection[1].clone());
+        adjecency.entry(connection[1].clone()).or_default().insert(connection[0].clone());
+        let mut clique = vec![connection[0].clone(), connection[1].clone()];
+        clique.sort();
+        cliques2.insert(clique);
+    }
+
+    let mut cliques: HashMap<u64, HashSet<Clique>> = HashMap::from([(2, cliques2)]);
+    for k in 3.. {
+        if cliques[&(k-1)].is_empty() || max_k.is_some_and(|max_k| k > max_k) {
+            break;
+        }
+        let mut cliques_k: HashSet<Clique> = Default::default();
+        for clique in &cliques[&(k-1)] {
+            'candidates: for candidate in adjecency.get(&clique[0]).unwrap_or(&HashSet::new())  {
+                for node in clique.iter().skip(1) {
+                    if !adjecency.get(node).unwrap_or(&HashSet::new()).contains(candidate) {
+                        continue 'candidates;
+                    }
+                }
+                let mut clique = clique.clone();
+                clique.push(candidate.clone());
+                clique.sort();
+                cliques_k.insert(clique);
+            }
+        }
+        cliques.insert(k, cliques_k);
+    }
+
+    cliques
+}
+
+fn parse_input(input: &str) -> Result<Vec<Connection>> {
+    input
+        .lines()
+        .map(|line| -> Result<Connection> {
+            let mut split = line.split('-');
+            Ok([split.next().unwrap().to_owned(), split.next().unwrap().to_owned()])
+        })
+        .try_collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 7.into());
+        assert_eq!(Day.part2(&input).unwrap(), "co,de,ka,ta".into());
+    }
+}
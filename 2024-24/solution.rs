@@ -0,0 +1,223 @@
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use lib::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+type Node = String;
+
+pub struct Puzzle {
+    inputs: HashMap<Node, bool>,
+    connections: HashMap<Node, Connection>
+}
+
+struct Connection {
+    a: Node,
+    b: Node,
+    operator: Operator,
+}
+
+#[derive(Debug, PartialEq)]
+enum Operator {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Puzzle;
+
+    fn parse(&self, input: &str) -> Result<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Puzzle) -> Result<Answer> {
+        let mut values: HashMap<&str, Option<bool>> = Default::default();
+        for (node, &value) in &input.inputs {
+            values.insert(node, Some(value));
+        }
+
+        for node in input.connections.keys() {
+            values.insert(node, None);
+        }
+
+        let mut result_bits = vec![];
+        for node in input.connections.keys() {
+            if !node.starts_with("z") {
+                continue
+            }
+            result_bits.push((node, evaluate(node, &mut values, &input.connections)));
+        }
+
+        result_bits.sort_by_key(|x| x.0);
+        result_bits.reverse();
+
+        Ok(to_decimal(result_bits.iter().map(|x| x.1).collect_vec()).into())
+    }
+
+    fn part2(&self, input: &Puzzle) -> Result<Answer> {
+        // the circuit is a ripple carry adder, every gate breaking its structure has a swapped output
+        let last_z = input.connections.keys().filter(|node| node.starts_with("z")).max().context("no outputs")?;
+        let is_input = |node: &Node| node.starts_with("x") || node.starts_with("y");
+        let is_first_bit = |connection: &Connection| connection.a.ends_with("00") && connection.b.ends_with("00");
+        let feeds = |node: &Node, operator: Operator| input.connections.values()
+            .any(|c| c.operator == operator && (&c.a == node || &c.b == node));
+
+        let mut swapped = vec![];
+        for (node, connection) in &input.connections {
+            let wrong = match connection.operator {
+                // every output bit but the final carry is a sum
+                _ if node.starts_with("z") && node != last_z =>
+                    connection.operator != Operator::Xor || (is_input(&connection.a) && !is_first_bit(connection)),
+                _ if node == last_z => connection.operator != Operator::Or,
+                // sum of input bits feeds the output sum, sum with carry is an output
+                Operator::Xor if is_input(&connection.a) => !is_first_bit(connection) && !feeds(node, Operator::Xor),
+                Operator::Xor => true,
+                // every carry part feeds the carry
+                Operator::And => !is_first_bit(connection) && !feeds(node, Operator::Or),
+                Operator::Or => false,
+            };
+            if wrong {
+                swapped.push(node.as_str());
+            }
+        }
+
+        swapped.sort();
+        Ok(swapped.join(",").into())
+    }
+}
+
+fn to_decimal(result_bits: Vec<bool>) -> u64 {
+    let mut result = 0;
+    for value in result_bits {
+        result *= 2;
+        if value {
+            result += 1
+        }
+    }
+    result
+}
+
+fn evaluate<'a>(node: &'a Node, values: &mut HashMap<&'a str, Option<bool>>, connections: &'a HashMap<String, Connection>) -> bool {
+    if let Some(value) = values[node.as_str()] {
+        return value;
+    }
+    let connection = &connections[node];
+    let a = evaluate(&connection.a, values, connections);
+    let b = evaluate(&connection.b, values, connections);
+    let value = match connection.operator {
+        Operator::And => a && b,
+        Operator::Or => a || b,
+        Operator::Xor => a != b,
+    };
+    values.insert(node, Some(value));
+    value
+}
+
+fn parse_input(input: &str) -> Result<Puzzle> {
+    let mut lines = input.lines();
+    let mut inputs: HashMap<Node, bool> = Default::default();
+    for line in (&mut lines).take_while(|line| !line.is_empty()) {
+        let split = line.split(": ").collect_vec();
+        inputs.insert(split[0].to_string(), split[1] == "1");
+    }
+
+    let mut connections: HashMap<Node, Connection> = Default::default();
+    for line in lines {
+        let split = line.split(" ").collect_vec();
+        connections.insert(split[4].to_string(), Connection{
+            a: split[0].to_string(),
+            b: split[2].to_string(),
+            operator: match split[1] {
+                "AND" => Operator::And,
+                "OR" => Operator::Or,
+                "XOR" => Operator::Xor,
+                _ => bail!("unexpected operator"),
+            },
+        });
+    }
+
+    Ok(Puzzle{
+        inputs,
+        connections,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+    use rstest::rstest;
+
+    const EXAMPLE_SMALL: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    const EXAMPLE_LARGE: &str = "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+";
+
+    #[rstest]
+    #[case(EXAMPLE_SMALL, 4)]
+    #[case(EXAMPLE_LARGE, 2024)]
+    fn test_example_part1(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(Day.part1(&Day.parse(input).unwrap()).unwrap(), expected.into());
+    }
+}
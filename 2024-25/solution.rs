@@ -0,0 +1,144 @@
+use anyhow::{Result, Ok};
+use lib::grid::{Coord, Grid, OFFSET_RIGHT, OFFSET_UP};
+use itertools::Itertools;
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Grid<char>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Grid<char>>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<Grid<char>>) -> Result<Answer> {
+        let mut lock_heights: Vec<Vec<u64>> = vec![];
+        let mut key_heights: Vec<Vec<u64>> = vec![];
+
+        for item in input {
+            let (is_key, heights) = to_heights(item);
+            if is_key {
+                key_heights.push(heights);
+            } else {
+                lock_heights.push(heights);
+            }
+        }
+
+        let mut sum: u64 = 0;
+        for lock in &lock_heights {
+            for key in &key_heights {
+                if is_viable(lock, key) {
+                    sum += 1
+                }
+            }
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(&self, _input: &Vec<Grid<char>>) -> Result<Answer> {
+        // the last day has only one puzzle
+        Ok("-".into())
+    }
+}
+
+fn is_key(grid: &Grid<char>) -> bool {
+    grid.iter_line(Coord { x: 0, y: 0 }, OFFSET_RIGHT)
+        .all(|(_, &x)| x == '.')
+}
+
+fn to_heights(grid: &Grid<char>) -> (bool, Vec<u64>) {
+    let is_key = is_key(grid);
+    (
+        is_key,
+        (0..grid.columns())
+            .map(|col| {
+                grid.iter_line(
+                    Coord {
+                        x: col as i64,
+                        y: (grid.rows() - 1) as i64,
+                    },
+                    OFFSET_UP,
+                )
+                .filter(|(_, &x)| (x == '#') == is_key)
+                .count() as u64
+            })
+            .collect_vec(),
+    )
+}
+
+fn is_viable(lock: &[u64], key: &[u64]) -> bool {
+    (0..lock.len()).all(|i| lock[i] >= key[i])
+}
+
+fn parse_input(input: &str) -> Result<Vec<Grid<char>>> {
+    let mut lines = input.lines();
+    let mut grids: Vec<Grid<char>> = vec![];
+    loop {
+        let grid_lines = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty());
+        let grid = Grid::from_lines_iter(grid_lines.map(str::chars))?;
+        if grid.columns() == 0 {
+            break;
+        }
+        grids.push(grid);
+    }
+
+    Ok(grids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day.part1(&Day.parse(EXAMPLE).unwrap()).unwrap(), 3.into());
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{bail, Context, Result};
-use std::io::stdin;
+use lib::solution::{Answer, Solution};
 
 struct Dial {
     num_count: i32,
@@ -7,6 +7,13 @@ struct Dial {
 }
 
 impl Dial {
+    fn new() -> Self {
+        Dial {
+            num_count: 100,
+            state: 50,
+        }
+    }
+
     fn do_move_with_through_count(&mut self, move_: Move, checked_through_number: i32) -> i32 {
         let orig_state = self.state;
         match move_.direction {
@@ -48,39 +55,49 @@ enum Direction {
 }
 
 #[derive(Copy, Clone)]
-struct Move {
+pub struct Move {
     direction: Direction,
     count: i32,
 }
 
-fn main() -> Result<()> {
-    let move_iter = parse_input();
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Move>;
 
-    let mut dial = Dial {
-        num_count: 100,
-        state: 50,
-    };
+    fn parse(&self, input: &str) -> Result<Vec<Move>> {
+        parse_input(input).collect()
+    }
 
-    let mut sum_part_1 = 0;
-    let mut sum_part_2 = 0;
+    fn part1(&self, input: &Vec<Move>) -> Result<Answer> {
+        let mut dial = Dial::new();
 
-    for move_ in move_iter {
-        let move_ = move_?;
-        sum_part_2 += dial.do_move_with_through_count(move_, 0);
-        if dial.state == 0 {
-            sum_part_1 += 1;
+        let mut sum = 0;
+        for &move_ in input {
+            dial.do_move_with_through_count(move_, 0);
+            if dial.state == 0 {
+                sum += 1;
+            }
         }
+
+        Ok(sum.into())
     }
 
-    println!("{}", sum_part_1);
-    println!("{}", sum_part_2);
+    fn part2(&self, input: &Vec<Move>) -> Result<Answer> {
+        let mut dial = Dial::new();
 
-    Ok(())
+        let mut sum = 0;
+        for &move_ in input {
+            sum += dial.do_move_with_through_count(move_, 0);
+        }
+
+        Ok(sum.into())
+    }
 }
 
-fn parse_input() -> impl Iterator<Item = Result<Move>> {
-    stdin().lines().map(|line| -> Result<Move> {
-        let line = line?;
+fn parse_input(input: &str) -> impl Iterator<Item = Result<Move>> + '_ {
+    input.lines().map(|line| -> Result<Move> {
         let (dir_str, num_str) = line
             .split_at_checked(1)
             .context("expected at least 2 chars on line")?;
@@ -90,9 +107,35 @@ fn parse_input() -> impl Iterator<Item = Result<Move>> {
             _ => bail!("expected L or R direction, got {}", dir_str),
         };
         let num = num_str.parse()?;
-        return Ok(Move {
+        Ok(Move {
             direction: dir,
             count: num,
-        });
+        })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 3.into());
+        assert_eq!(Day.part2(&input).unwrap(), 6.into());
+    }
+}
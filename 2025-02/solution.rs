@@ -0,0 +1,97 @@
+use anyhow::{Context, Result};
+use lib::solution::{Answer, Solution};
+use std::ops::RangeInclusive;
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<RangeInclusive<i64>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<RangeInclusive<i64>>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<RangeInclusive<i64>>) -> Result<Answer> {
+        Ok(invalid_ids_sum(input, is_invalid_id_part1).into())
+    }
+
+    fn part2(&self, input: &Vec<RangeInclusive<i64>>) -> Result<Answer> {
+        Ok(invalid_ids_sum(input, is_invalid_id_part2).into())
+    }
+}
+
+fn invalid_ids_sum(ranges: &[RangeInclusive<i64>], is_invalid_id: fn(i64) -> bool) -> i64 {
+    let mut sum = 0;
+
+    for range in ranges {
+        for id in range.clone() {
+            if is_invalid_id(id) {
+                sum += id
+            }
+        }
+    }
+
+    sum
+}
+
+fn is_invalid_id_part1(v: i64) -> bool {
+    let s = v.to_string();
+    if !s.len().is_multiple_of(2) {
+        return false;
+    }
+    let half_len = s.len() / 2;
+    let magnitude = 10_i64.pow(half_len as u32);
+    v / magnitude == v % magnitude
+}
+
+fn is_invalid_id_part2(v: i64) -> bool {
+    let s = v.to_string();
+    let half_len = s.len() / 2;
+    'outer: for sequence_len in 1..=half_len {
+        if !s.len().is_multiple_of(sequence_len) {
+            continue;
+        }
+        let magnitude = 10_i64.pow(sequence_len as u32);
+        let repeated = v % magnitude;
+        let mut remaining = v;
+        while remaining > 0 {
+            if remaining % magnitude != repeated {
+                continue 'outer;
+            }
+            remaining /= magnitude
+        }
+        return true;
+    }
+    false
+}
+
+fn parse_input(input: &str) -> Result<Vec<RangeInclusive<i64>>> {
+    input
+        .trim_end()
+        .split(",")
+        .map(|s| -> Result<RangeInclusive<i64>> {
+            let mut range_strs = s.splitn(2, "-");
+            let range_start = range_strs.next().context("expected range start")?.parse()?;
+            let range_end = range_strs.next().context("expected range end")?.parse()?;
+            Ok(range_start..=range_end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 1227775554.into());
+        assert_eq!(Day.part2(&input).unwrap(), 4174379265u64.into());
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u8>>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<Vec<u8>>) -> Result<Answer> {
+        Ok(input.iter().map(|bank| bank_joltage(bank, 2)).sum::<i64>().into())
+    }
+
+    fn part2(&self, input: &Vec<Vec<u8>>) -> Result<Answer> {
+        Ok(input.iter().map(|bank| bank_joltage(bank, 12)).sum::<i64>().into())
+    }
+}
+
+fn bank_joltage(bank: &[u8], digits: usize) -> i64 {
+    let mut first_usable_index = 0;
+    let mut result: i64 = 0;
+    for digit in 0..digits {
+        let digit_candidates = &bank[first_usable_index..bank.len() - digits + digit + 1];
+        let max_digit_position = first_usable_index
+            + digit_candidates
+                .iter()
+                .position(|&v| v == *digit_candidates.iter().max().unwrap())
+                .unwrap();
+        first_usable_index = max_digit_position + 1;
+        result *= 10;
+        result += bank[max_digit_position] as i64;
+    }
+    result
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u8>>> {
+    input
+        .lines()
+        .map(|line| -> Result<Vec<u8>> {
+            Ok(line.as_bytes().iter().map(|&c| c - b'0').collect())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 357.into());
+        assert_eq!(Day.part2(&input).unwrap(), 3121910778619u64.into());
+    }
+}
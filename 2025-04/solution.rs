@@ -0,0 +1,73 @@
+use anyhow::Result;
+use lib::grid::{Automaton, Connectivity, Grid, Neighbourhood};
+use lib::grid_tile;
+use lib::solution::{Answer, Solution};
+
+#[derive(Clone, PartialEq)]
+pub enum Point {
+    Empty,
+    Roll,
+}
+
+grid_tile!(Point {
+    '.' => Point::Empty,
+    '@' => Point::Roll,
+});
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<Point>;
+
+    fn parse(&self, input: &str) -> Result<Grid<Point>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Grid<Point>) -> Result<Answer> {
+        Ok(removing_rolls(input).step().into())
+    }
+
+    fn part2(&self, input: &Grid<Point>) -> Result<Answer> {
+        Ok(removing_rolls(input).run_until_stable().iter().sum::<usize>().into())
+    }
+}
+
+// every step removes the rolls accessible by having fewer than four rolls around
+fn removing_rolls(grid: &Grid<Point>) -> Automaton<Point, impl FnMut(&Point, Neighbourhood<'_, Point>) -> Point> {
+    Automaton::new(grid.clone(), Connectivity::Eight, |point, neighbours| match point {
+        Point::Roll if neighbours.count(|n| *n == Point::Roll) < 4 => Point::Empty,
+        _ => point.clone(),
+    })
+}
+
+fn parse_input(input: &str) -> Result<Grid<Point>> {
+    Ok(Grid::parse_str(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(input.to_string(), EXAMPLE);
+        assert_eq!(Day.part1(&input).unwrap(), 13.into());
+        assert_eq!(Day.part2(&input).unwrap(), 43.into());
+    }
+}
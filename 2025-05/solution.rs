@@ -0,0 +1,102 @@
+use anyhow::{Context, Result};
+use lib::interval::IntervalSet;
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Database {
+    fresh: IntervalSet<i64>,
+    available: Vec<i64>,
+}
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Database;
+
+    fn parse(&self, input: &str) -> Result<Database> {
+        parse_input(input)
+    }
+
+    fn part1(&self, database: &Database) -> Result<Answer> {
+        let count = database
+            .available
+            .iter()
+            .filter(|&&id| database.fresh.contains(id))
+            .count();
+
+        Ok(count.into())
+    }
+
+    fn part2(&self, database: &Database) -> Result<Answer> {
+        let count = database.fresh.len();
+
+        Ok(count.into())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Database> {
+    let mut available_phase = false;
+    let mut result = Database::default();
+    for line in input.lines() {
+        if !available_phase {
+            if line.is_empty() {
+                available_phase = true;
+                continue;
+            }
+            let mut range_strs = line.splitn(2, "-");
+            let range_start = range_strs
+                .next()
+                .context("expected range start")?
+                .parse()
+                .with_context(|| format!("parsing range start, got '{}'", line))?;
+            let range_end = range_strs
+                .next()
+                .context("expected range end")?
+                .parse()
+                .with_context(|| format!("parsing range end, got '{}'", line))?;
+            result
+                .fresh
+                .insert_inclusive(range_start..=range_end)
+                .with_context(|| format!("range end too large, got '{}'", line))?;
+        } else {
+            result.available.push(
+                line.parse()
+                    .with_context(|| format!("parsing available ingredient, got '{}'", line))?,
+            );
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 3.into());
+        assert_eq!(Day.part2(&input).unwrap(), 14.into());
+    }
+
+    #[test]
+    fn test_range_to_largest_id() {
+        assert!(Day.parse("3-9223372036854775807\n\n5\n").is_err());
+    }
+}
@@ -1,6 +1,12 @@
 use anyhow::{bail, Context, Result};
 use itertools::Itertools;
-use std::io::stdin;
+use lib::solution::{Answer, Solution};
+
+// numbers are read from rows in part 1, but from columns in part 2
+pub struct Worksheets {
+    by_rows: Worksheet,
+    by_columns: Worksheet,
+}
 
 #[derive(Default, Debug)]
 struct Worksheet {
@@ -40,23 +46,33 @@ enum Operation {
     Mul,
 }
 
-fn main() -> Result<()> {
-    // only one of these can be run, as they consume stdin input
-    // let worksheet = parse_input()?;
-    let worksheet2 = parse_input_part2()?;
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Worksheets;
 
-    // println!("{}", worksheet.sum());
-    println!("{}", worksheet2.sum());
+    fn parse(&self, input: &str) -> Result<Worksheets> {
+        Ok(Worksheets {
+            by_rows: parse_input(input)?,
+            by_columns: parse_input_part2(input)?,
+        })
+    }
+
+    fn part1(&self, input: &Worksheets) -> Result<Answer> {
+        Ok(input.by_rows.sum().into())
+    }
 
-    Ok(())
+    fn part2(&self, input: &Worksheets) -> Result<Answer> {
+        Ok(input.by_columns.sum().into())
+    }
 }
 
-fn parse_input() -> Result<Worksheet> {
+fn parse_input(input: &str) -> Result<Worksheet> {
     let mut result = Worksheet::default();
-    for line in stdin().lines() {
-        let line = line?;
+    for line in input.lines() {
         let split = line.split_whitespace().collect_vec();
-        if result.cols.len() == 0 {
+        if result.cols.is_empty() {
             for _ in &split {
                 result.cols.push(WorksheetColumn::default())
             }
@@ -85,10 +101,10 @@ fn parse_input() -> Result<Worksheet> {
     Ok(result)
 }
 
-fn parse_input_part2() -> Result<Worksheet> {
+fn parse_input_part2(input: &str) -> Result<Worksheet> {
     let mut result = Worksheet::default();
     result.cols.push(WorksheetColumn::default());
-    let lines = stdin().lines().collect::<Result<Vec<String>, _>>()?;
+    let lines = input.lines().collect_vec();
     let mut col_i = 0;
     for byte_i in 0..lines[0].len() {
         let mut num: i64 = 0;
@@ -125,3 +141,23 @@ fn parse_input_part2() -> Result<Worksheet> {
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 4277556.into());
+        assert_eq!(Day.part2(&input).unwrap(), 3263827.into());
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::{HashMap};
+use anyhow::Result;
+use lib::grid::{Coord, Grid};
+use lib::grid_tile;
+use lib::solution::{Answer, Solution};
+
+pub enum Point {
+    Empty,
+    Start,
+    Splitter,
+}
+
+grid_tile!(Point {
+    '.' => Point::Empty,
+    'S' => Point::Start,
+    '^' => Point::Splitter,
+});
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<Point>;
+
+    fn parse(&self, input: &str) -> Result<Grid<Point>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, grid: &Grid<Point>) -> Result<Answer> {
+        let (split_count, _) = propagate_rays(grid);
+
+        Ok(split_count.into())
+    }
+
+    fn part2(&self, grid: &Grid<Point>) -> Result<Answer> {
+        let (_, end_rays) = propagate_rays(grid);
+
+        Ok(end_rays.values().sum::<i64>().into())
+    }
+}
+
+fn propagate_rays(grid: &Grid<Point>) -> (i64, HashMap<usize, i64>) {
+    let mut result = HashMap::default();
+    let mut split_count = 0;
+
+    for y in 0..grid.rows() {
+        for x in 0..grid.columns() {
+            let coord = Coord{x: x as i64, y: y as i64};
+            match grid[coord] {
+                Point::Empty => {}
+                Point::Start => {result.insert(x, 1);}
+                Point::Splitter => {
+                    if let Some(removed) = result.remove(&x) {
+                        split_count += 1;
+                        if x > 0 {
+                            *result.entry(x-1).or_default() += removed;
+                        }
+                        if x < grid.columns()-1 {
+                            *result.entry(x+1).or_default() += removed;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (split_count, result)
+}
+
+fn parse_input(input: &str) -> Result<Grid<Point>> {
+    Ok(Grid::parse_str(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 21.into());
+        assert_eq!(Day.part2(&input).unwrap(), 40.into());
+    }
+}
@@ -0,0 +1,155 @@
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use lib::params::Params;
+use lib::solution::{Answer, Solution};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Point([i64; 3]);
+
+impl Point {
+    fn distance(&self, other: &Self) -> i64 {
+        (self.0[0].abs_diff(other.0[0]).pow(2)
+            + self.0[1].abs_diff(other.0[1]).pow(2)
+            + self.0[2].abs_diff(other.0[2]).pow(2)) as i64
+    }
+}
+
+pub struct Day {
+    // closest pairs connected in part 1
+    pairs: usize,
+}
+
+impl Default for Day {
+    fn default() -> Self {
+        Day { pairs: 1000 }
+    }
+}
+
+impl Solution for Day {
+    type Input = Vec<Point>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Point>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, points: &Vec<Point>) -> Result<Answer> {
+        let (circuits, _) = connect_circuits(points, Some(self.pairs))?;
+
+        let mut circuits = circuits.iter().flatten().collect_vec();
+        circuits.sort_by_key(|c| Reverse(c.len()));
+
+        Ok((circuits[0].len() * circuits[1].len() * circuits[2].len()).into())
+    }
+
+    fn part2(&self, points: &Vec<Point>) -> Result<Answer> {
+        let (_, last_connected_i) = connect_circuits(points, None)?;
+
+        Ok((points[last_connected_i.0].0[0] * points[last_connected_i.1].0[0]).into())
+    }
+
+    fn params(&mut self) -> Params<'_> {
+        Params::new().with("pairs", &mut self.pairs)
+    }
+}
+
+// connects the closest pairs of points, returning the circuits and the last pair that merged two of them
+#[allow(clippy::type_complexity)]
+fn connect_circuits(
+    points: &[Point],
+    pair_limit: Option<usize>,
+) -> Result<(Vec<Option<HashSet<usize>>>, (usize, usize))> {
+    let mut distances = Vec::with_capacity(points.len() * (points.len() - 1) / 2);
+
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            distances.push((i, j));
+        }
+    }
+
+    distances.sort_by_key(|&(i, j)| points[i].distance(&points[j]));
+
+    let mut circuits = Vec::from_iter((0..points.len()).map(|i| Some(HashSet::from([i]))));
+
+    let mut circuits_map = Vec::from_iter(0..points.len());
+
+    let mut last_connected_i = (0, 0);
+
+    for (i, j) in distances.into_iter().take(pair_limit.unwrap_or(usize::MAX)) {
+        if circuits[circuits_map[i]]
+            .as_ref()
+            .context("primary circuit is already unused")?
+            .contains(&j)
+        {
+            continue;
+        }
+        last_connected_i = (i, j);
+        let other_circuit_i = circuits_map[j];
+        let absorbed_set = circuits[other_circuit_i]
+            .take()
+            .context("expected still-used set, got None")?;
+        for other_point_i in absorbed_set {
+            circuits[circuits_map[i]]
+                .as_mut()
+                .context("inserting into empty unused circuit")?
+                .insert(other_point_i);
+            circuits_map[other_point_i] = circuits_map[i];
+        }
+    }
+
+    Ok((circuits, last_connected_i))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point>> {
+    input
+        .lines()
+        .map(|line| {
+            let parsed_vec: Vec<i64> = line
+                .split(",")
+                .map(|s| s.parse().with_context(|| format!("parsing '{}'", s)))
+                .try_collect()?;
+            if parsed_vec.len() != 3 {
+                bail!("expected 3 coordinates, got '{}'", parsed_vec.len());
+            }
+            Ok(Point([parsed_vec[0], parsed_vec[1], parsed_vec[2]]))
+        })
+        .try_collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    #[test]
+    fn test_example() {
+        let day = Day { pairs: 10 };
+        let input = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&input).unwrap(), 40.into());
+        assert_eq!(day.part2(&input).unwrap(), 25272.into());
+    }
+}
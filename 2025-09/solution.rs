@@ -0,0 +1,116 @@
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use lib::geometry::{Polygon, Rect};
+use lib::grid::{Compression, Coord, Grid, Offset};
+use lib::solution::{Answer, Solution};
+
+#[derive(Default)]
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Coord>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Coord>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, pole_coords: &Vec<Coord>) -> Result<Answer> {
+        let mut max_area = 0;
+
+        for i in 0..pole_coords.len() - 1 {
+            for j in i + 1..pole_coords.len() {
+                let area = Rect::new(pole_coords[i], pole_coords[j]).lattice_area();
+                if area > max_area {
+                    max_area = area;
+                }
+            }
+        }
+
+        Ok(max_area.into())
+    }
+
+    fn part2(&self, pole_coords: &Vec<Coord>) -> Result<Answer> {
+        let (compression, inside) = inside_cells(pole_coords).context("no poles")?;
+        let outside_cells = inside.prefix_sums(|_, &inside| u32::from(!inside));
+        let mut max_area = 0;
+
+        for i in 0..pole_coords.len() - 1 {
+            for j in i + 1..pole_coords.len() {
+                let rect = Rect::new(pole_coords[i], pole_coords[j]);
+                let area = rect.lattice_area();
+                if area > max_area && outside_cells.sum(compression.compress_rect(rect).expect("poles are compressed")) == 0 {
+                    max_area = area;
+                }
+            }
+        }
+
+        Ok(max_area.into())
+    }
+}
+
+// tiles inside the loop of poles or on it, on the grid of poles' rows and columns and the gaps between
+fn inside_cells(pole_coords: &[Coord]) -> Option<(Compression, Grid<bool>)> {
+    // a ring of cells around the poles, to flood the outside from
+    let bounds = Rect::from_points(pole_coords.iter().copied())?;
+    let padding = [bounds.min + Offset { x: -1, y: -1 }, bounds.max + Offset { x: 1, y: 1 }];
+    let compression = Compression::new(pole_coords.iter().copied().chain(padding));
+
+    let mut walls = compression.grid(false);
+    for (from, to) in Polygon::new(pole_coords.to_vec()).edges() {
+        let cells = compression.compress_rect(Rect::new(from, to)).expect("poles are compressed");
+        for y in cells.min.y..=cells.max.y {
+            for x in cells.min.x..=cells.max.x {
+                walls[Coord { x, y }] = true;
+            }
+        }
+    }
+    let outside = walls.distance_field([Coord::default()], |&wall| !wall);
+    let mut inside = compression.grid(false);
+    for (cell, distance) in outside.iter() {
+        inside[cell] = distance.is_none();
+    }
+    Some((compression, inside))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Coord>> {
+    input
+        .lines()
+        .map(|line| {
+            let parsed_vec: Vec<i64> = line
+                .split(",")
+                .map(|s| s.parse().with_context(|| format!("parsing '{}'", s)))
+                .try_collect()?;
+            if parsed_vec.len() != 2 {
+                bail!("expected 2 coordinates, got '{}'", parsed_vec.len());
+            }
+            Ok(Coord {
+                x: parsed_vec[0],
+                y: parsed_vec[1],
+            })
+        })
+        .try_collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use lib::solution::Solution;
+
+    const EXAMPLE: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day.parse(EXAMPLE).unwrap();
+        assert_eq!(Day.part1(&input).unwrap(), 50.into());
+        assert_eq!(Day.part2(&input).unwrap(), 24.into());
+    }
+}
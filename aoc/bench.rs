@@ -0,0 +1,215 @@
+use crate::days::Day;
+use anyhow::{Context, Result};
+use lib::input::Inputs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+}
+
+impl Timing {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Timing {
+            median: samples[samples.len() / 2],
+            min: samples[0],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayTimings {
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing,
+}
+
+impl DayTimings {
+    fn stages(&self) -> [(&'static str, Timing); 3] {
+        [("parse", self.parse), ("part 1", self.part1), ("part 2", self.part2)]
+    }
+}
+
+// timings of days by their name, as stored in a baseline
+pub type Baseline = BTreeMap<String, DayTimings>;
+
+// times each stage separately, parsing anew in every iteration
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<DayTimings> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    let solution = day.solution();
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed = solution.parse_dyn(input).context("parsing input")?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        parsed.part1().context("part 1")?;
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        parsed.part2().context("part 2")?;
+        part2.push(start.elapsed());
+    }
+
+    Ok(DayTimings {
+        parse: Timing::from_samples(parse),
+        part1: Timing::from_samples(part1),
+        part2: Timing::from_samples(part2),
+    })
+}
+
+pub fn bench<'a>(
+    days: impl IntoIterator<Item = &'a Day>,
+    inputs: &Inputs,
+    iterations: usize,
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> Result<(Baseline, usize)> {
+    let mut timings = Baseline::new();
+    let mut regressions = 0;
+
+    println!(
+        "{:<8} {:>21} {:>21} {:>21}",
+        "day", "parse median/min", "part 1 median/min", "part 2 median/min"
+    );
+    for day in days {
+        let Some(source) = inputs.find(day.year, day.day, &day.dir()) else {
+            println!("{:<8} skipped, no input", day.name());
+            continue;
+        };
+        let day_timings = bench_day(day, &source.read()?, iterations)
+            .with_context(|| format!("running {}", day.name()))?;
+
+        let cells = day_timings.stages().map(|(_, timing)| {
+            format!("{} / {}", format_duration(timing.median), format_duration(timing.min))
+        });
+        println!("{:<8} {:>21} {:>21} {:>21}", day.name(), cells[0], cells[1], cells[2]);
+
+        if let Some(previous) = baseline.and_then(|baseline| baseline.get(&day.name())) {
+            for ((stage, timing), (_, previous)) in day_timings.stages().into_iter().zip(previous.stages()) {
+                if is_regression(previous, timing, threshold) {
+                    regressions += 1;
+                    println!(
+                        "{:<8} {stage} regressed: median {} -> {}",
+                        "",
+                        format_duration(previous.median),
+                        format_duration(timing.median)
+                    );
+                }
+            }
+        }
+
+        timings.insert(day.name(), day_timings);
+    }
+
+    Ok((timings, regressions))
+}
+
+// slower by more than the threshold ratio, e.g. 0.1 for 10%
+pub fn is_regression(previous: Timing, current: Timing, threshold: f64) -> bool {
+    current.median.as_secs_f64() > previous.median.as_secs_f64() * (1.0 + threshold)
+}
+
+pub fn load_baseline(path: &Path) -> Result<Baseline> {
+    let content = fs::read_to_string(path).with_context(|| format!("reading baseline {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("parsing baseline {}", path.display()))
+}
+
+// keeps timings of days that were not benchmarked this time
+pub fn save_baseline(path: &Path, previous: Option<Baseline>, timings: Baseline) -> Result<()> {
+    let mut baseline = previous.unwrap_or_default();
+    baseline.extend(timings);
+    let content = serde_json::to_string_pretty(&baseline)?;
+    fs::write(path, content + "\n").with_context(|| format!("writing baseline {}", path.display()))
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+// durations as integer nanoseconds in the JSON baseline
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_nanos(u64::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{format_duration, is_regression, Baseline, DayTimings, Timing};
+    use rstest::rstest;
+    use std::time::Duration;
+
+    fn timing(median_micros: u64) -> Timing {
+        Timing {
+            median: Duration::from_micros(median_micros),
+            min: Duration::from_micros(median_micros / 2),
+        }
+    }
+
+    #[test]
+    fn timing_takes_median_and_min() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        assert_eq!(
+            Timing::from_samples(samples),
+            Timing {
+                median: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(100, 105, false)]
+    #[case(100, 111, true)]
+    #[case(100, 50, false)]
+    fn regression_over_threshold(#[case] previous: u64, #[case] current: u64, #[case] expected: bool) {
+        assert_eq!(is_regression(timing(previous), timing(current), 0.1), expected);
+    }
+
+    #[rstest]
+    #[case(Duration::from_nanos(999), "999ns")]
+    #[case(Duration::from_micros(1500), "1.5ms")]
+    #[case(Duration::from_millis(2345), "2.35s")]
+    fn formats_duration(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(format_duration(duration), expected);
+    }
+
+    #[test]
+    fn baseline_round_trips_through_json() {
+        let baseline = Baseline::from([(
+            "2024-16".to_string(),
+            DayTimings {
+                parse: timing(10),
+                part1: timing(2000),
+                part2: timing(3000),
+            },
+        )]);
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
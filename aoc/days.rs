@@ -0,0 +1,169 @@
+use anyhow::{Context, Result};
+use lib::solution::{Answer, DynSolution};
+use std::path::{Path, PathBuf};
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    // path of the solution source, relative to this file
+    source: &'static str,
+    // solution with the parameters of the real input
+    new_solution: fn() -> Box<dyn DynSolution>,
+}
+
+impl Day {
+    // directory holding the solution, e.g. "2023-5" or "2024-05"
+    pub fn dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(Path::new(self.source).parent().unwrap().file_name().unwrap())
+    }
+
+    pub fn name(&self) -> String {
+        format!("{}-{}", self.year, self.day)
+    }
+
+    pub fn solution(&self) -> Box<dyn DynSolution> {
+        (self.new_solution)()
+    }
+
+    // solution with some of its parameters changed, e.g. to run an example
+    pub fn solution_with(&self, params: &[(String, String)]) -> Result<Box<dyn DynSolution>> {
+        let mut solution = self.solution();
+        let mut known = solution.params_dyn();
+        for (name, value) in params {
+            known.set(name, value)?;
+        }
+        drop(known);
+        Ok(solution)
+    }
+
+    // parameter names with their values for the real input
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        self.solution().params_dyn().values()
+    }
+
+    pub fn solve(&self, input: &str) -> Result<(Answer, Answer)> {
+        self.solve_with(&[], input)
+    }
+
+    pub fn solve_with(&self, params: &[(String, String)], input: &str) -> Result<(Answer, Answer)> {
+        let solution = self.solution_with(params)?;
+        let parsed = solution.parse_dyn(input).context("parsing input")?;
+        let part1 = parsed.part1().context("part 1")?;
+        let part2 = parsed.part2().context("part 2")?;
+        Ok((part1, part2))
+    }
+}
+
+macro_rules! days {
+    ($($module:ident: $year:literal-$day:literal $path:literal,)*) => {
+        $(
+            #[path = $path]
+            mod $module;
+        )*
+
+        pub static DAYS: &[Day] = &[$(
+            Day {
+                year: $year,
+                day: $day,
+                source: $path,
+                new_solution: || Box::new(<$module::Day>::default()),
+            },
+        )*];
+    };
+}
+
+days! {
+    y2022_01: 2022-1 "../2022-1/solution.rs",
+    y2022_04: 2022-4 "../2022-4/solution.rs",
+    y2022_05: 2022-5 "../2022-5/solution.rs",
+    y2022_06: 2022-6 "../2022-6/solution.rs",
+    y2022_07: 2022-7 "../2022-7/solution.rs",
+    y2022_08: 2022-8 "../2022-8/solution.rs",
+    y2022_09: 2022-9 "../2022-9/solution.rs",
+    y2022_10: 2022-10 "../2022-10/solution.rs",
+    y2022_11: 2022-11 "../2022-11/solution.rs",
+    y2022_13: 2022-13 "../2022-13/solution.rs",
+    y2022_14: 2022-14 "../2022-14/solution.rs",
+    y2023_01: 2023-1 "../2023-1/solution.rs",
+    y2023_02: 2023-2 "../2023-2/solution.rs",
+    y2023_03: 2023-3 "../2023-3/solution.rs",
+    y2023_04: 2023-4 "../2023-4/solution.rs",
+    y2023_05: 2023-5 "../2023-5/solution.rs",
+    y2023_06: 2023-6 "../2023-6/solution.rs",
+    y2023_07: 2023-7 "../2023-7/solution.rs",
+    y2023_08: 2023-8 "../2023-8/solution.rs",
+    y2023_09: 2023-9 "../2023-9/solution.rs",
+    y2023_10: 2023-10 "../2023-10/solution.rs",
+    y2023_11: 2023-11 "../2023-11/solution.rs",
+    y2023_12: 2023-12 "../2023-12/solution.rs",
+    y2023_13: 2023-13 "../2023-13/solution.rs",
+    y2023_14: 2023-14 "../2023-14/solution.rs",
+    y2023_15: 2023-15 "../2023-15/solution.rs",
+    y2023_16: 2023-16 "../2023-16/solution.rs",
+    y2023_17: 2023-17 "../2023-17/solution.rs",
+    y2023_18: 2023-18 "../2023-18/solution.rs",
+    y2023_19: 2023-19 "../2023-19/solution.rs",
+    y2023_20: 2023-20 "../2023-20/solution.rs",
+    y2023_21: 2023-21 "../2023-21/solution.rs",
+    y2023_22: 2023-22 "../2023-22/solution.rs",
+    y2023_23: 2023-23 "../2023-23/solution.rs",
+    y2023_24: 2023-24 "../2023-24/solution.rs",
+    y2023_25: 2023-25 "../2023-25/solution.rs",
+    y2024_01: 2024-1 "../2024-01/solution.rs",
+    y2024_02: 2024-2 "../2024-02/solution.rs",
+    y2024_03: 2024-3 "../2024-03/solution.rs",
+    y2024_04: 2024-4 "../2024-04/solution.rs",
+    y2024_05: 2024-5 "../2024-05/solution.rs",
+    y2024_06: 2024-6 "../2024-06/solution.rs",
+    y2024_07: 2024-7 "../2024-07/solution.rs",
+    y2024_08: 2024-8 "../2024-08/solution.rs",
+    y2024_09: 2024-9 "../2024-09/solution.rs",
+    y2024_10: 2024-10 "../2024-10/solution.rs",
+    y2024_11: 2024-11 "../2024-11/solution.rs",
+    y2024_12: 2024-12 "../2024-12/solution.rs",
+    y2024_13: 2024-13 "../2024-13/solution.rs",
+    y2024_14: 2024-14 "../2024-14/solution.rs",
+    y2024_15: 2024-15 "../2024-15/solution.rs",
+    y2024_16: 2024-16 "../2024-16/solution.rs",
+    y2024_17: 2024-17 "../2024-17/solution.rs",
+    y2024_18: 2024-18 "../2024-18/solution.rs",
+    y2024_19: 2024-19 "../2024-19/solution.rs",
+    y2024_20: 2024-20 "../2024-20/solution.rs",
+    y2024_21: 2024-21 "../2024-21/solution.rs",
+    y2024_22: 2024-22 "../2024-22/solution.rs",
+    y2024_23: 2024-23 "../2024-23/solution.rs",
+    y2024_24: 2024-24 "../2024-24/solution.rs",
+    y2024_25: 2024-25 "../2024-25/solution.rs",
+    y2025_01: 2025-1 "../2025-01/solution.rs",
+    y2025_02: 2025-2 "../2025-02/solution.rs",
+    y2025_03: 2025-3 "../2025-03/solution.rs",
+    y2025_04: 2025-4 "../2025-04/solution.rs",
+    y2025_05: 2025-5 "../2025-05/solution.rs",
+    y2025_06: 2025-6 "../2025-06/solution.rs",
+    y2025_07: 2025-7 "../2025-07/solution.rs",
+    y2025_08: 2025-8 "../2025-08/solution.rs",
+    y2025_09: 2025-9 "../2025-09/solution.rs",
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::DAYS;
+
+    fn param(name: &str, value: &str) -> (String, String) {
+        (name.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn params_override_real_input_defaults() {
+        let day = DAYS.iter().find(|day| day.name() == "2023-11").unwrap();
+        assert_eq!(day.params(), [("expansion", "1000000".to_string())]);
+
+        let example = "...#......\n.......#..\n#.........\n..........\n......#...\n\
+                       .#........\n.........#\n..........\n.......#..\n#...#.....\n";
+        let (part1, part2) = day.solve_with(&[param("expansion", "10")], example).unwrap();
+        assert_eq!((part1, part2), (374.into(), 1030.into()));
+
+        assert!(day.solution_with(&[param("rows", "7")]).is_err());
+    }
+}
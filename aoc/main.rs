@@ -0,0 +1,270 @@
+mod bench;
+mod days;
+mod verify;
+
+use anyhow::{bail, Context, Result};
+use bench::{bench, load_baseline, save_baseline};
+use clap::{Parser, Subcommand};
+use days::{Day, DAYS};
+use lib::answers::AnswerStore;
+use lib::fetch::{session_token, FetchOutcome, Fetcher, DEFAULT_BASE_URL};
+use lib::input::{InputSource, Inputs};
+use lib::params::{load_fixture, parse_assignment};
+use lib::solution::Answer;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use verify::{default_answers_dir, print_report, verify};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    /// Directory of cached inputs, laid out as YYYY/DD.txt
+    #[arg(long, global = true, env = "AOC_INPUTS", default_value_os_t = default_inputs_dir())]
+    inputs: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day (e.g. 2024-16) or every day of a year (e.g. 2023)
+    ///
+    /// The input is taken from the input cache, then from the input file next to the solution. Stdin
+    /// is never read as a fallback, only when asked for with --input -.
+    Run {
+        selector: Selector,
+        /// Set a puzzle parameter of the day, e.g. rows=7, overriding the fixture
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_assignment)]
+        params: Vec<(String, String)>,
+        /// TOML file of puzzle parameters of the day, e.g. those of an example
+        #[arg(long, value_name = "FILE")]
+        fixture: Option<PathBuf>,
+        /// Read the input of the day from this file instead, or from stdin for - (the only way stdin is read)
+        #[arg(long, value_name = "FILE")]
+        input: Option<PathBuf>,
+    },
+    /// List registered days, optionally only those of one year
+    List { selector: Option<Selector> },
+    /// Download missing inputs of a day or of every registered day of a year into the input cache
+    Fetch {
+        selector: Selector,
+        /// Advent of Code server to download from
+        #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Check answers of every day, or of a year or a day, against the recorded ones
+    Verify {
+        selector: Option<Selector>,
+        /// Store the current answers as the recorded ones
+        #[arg(long)]
+        record: bool,
+        /// Directory of recorded answers, one YYYY.toml per year
+        #[arg(long, default_value_os_t = default_answers_dir())]
+        answers: PathBuf,
+    },
+    /// Time parsing and both parts of every day, or of a year or a day
+    Bench {
+        selector: Option<Selector>,
+        /// Number of timed runs of each day
+        #[arg(long, short = 'n', default_value_t = 10)]
+        iterations: usize,
+        /// Previous timings (JSON) to flag regressions against
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Relative slowdown of a median considered a regression
+        #[arg(long, default_value_t = 0.1)]
+        threshold: f64,
+        /// Write the timings (JSON) here, merged into the file if it exists
+        #[arg(long)]
+        save: Option<PathBuf>,
+    },
+}
+
+#[derive(Copy, Clone)]
+enum Selector {
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl FromStr for Selector {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s.split_once('-') {
+            None => Selector::Year(s.parse().with_context(|| format!("invalid year '{s}'"))?),
+            Some((year, day)) => Selector::Day(
+                year.parse().with_context(|| format!("invalid year '{year}'"))?,
+                day.parse().with_context(|| format!("invalid day '{day}'"))?,
+            ),
+        })
+    }
+}
+
+impl Selector {
+    fn matches(self, day: &Day) -> bool {
+        match self {
+            Selector::Year(year) => day.year == year,
+            Selector::Day(year, d) => day.year == year && day.day == d,
+        }
+    }
+
+    fn days(self) -> Result<Vec<&'static Day>> {
+        let days: Vec<_> = DAYS.iter().filter(|day| self.matches(day)).collect();
+        if days.is_empty() {
+            bail!("no solution registered for {self}")
+        }
+        Ok(days)
+    }
+}
+
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selector::Year(year) => write!(f, "{year}"),
+            Selector::Day(year, day) => write!(f, "{year}-{day}"),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let inputs = Inputs::new(cli.inputs);
+
+    match cli.command {
+        Command::Run {
+            selector,
+            params,
+            fixture,
+            input,
+        } => {
+            let mut all_params = match fixture {
+                Some(path) => load_fixture(&path)?,
+                None => vec![],
+            };
+            all_params.extend(params);
+            run(&inputs, selector, &all_params, input.as_deref())
+        }
+        Command::List { selector } => {
+            for day in DAYS.iter().filter(|day| selector.is_none_or(|s| s.matches(day))) {
+                let params: String = day.params().iter().map(|(name, value)| format!(" {name}={value}")).collect();
+                match inputs.find(day.year, day.day, &day.dir()) {
+                    Some(source) => println!("{} ({source}){params}", day.name()),
+                    None => println!("{} (no input){params}", day.name()),
+                }
+            }
+            Ok(())
+        }
+        Command::Fetch { selector, base_url } => fetch(&inputs, selector, &base_url),
+        Command::Verify {
+            selector,
+            record,
+            answers,
+        } => {
+            let (mut wrong, mut failed) = (0, 0);
+            verify(selector_days(selector)?, &inputs, &AnswerStore::new(answers), record, |day, report| {
+                print_report(day, report);
+                if report.is_wrong() {
+                    wrong += 1;
+                }
+                if report.is_failed() {
+                    failed += 1;
+                }
+            })?;
+            if failed > 0 {
+                bail!("{failed} of the checked days failed");
+            }
+            if wrong > 0 && !record {
+                bail!("wrong answers in {wrong} of the checked days");
+            }
+            Ok(())
+        }
+        Command::Bench {
+            selector,
+            iterations,
+            baseline,
+            threshold,
+            save,
+        } => {
+            let baseline = baseline.as_deref().map(load_baseline).transpose()?;
+            let (timings, regressions) =
+                bench(selector_days(selector)?, &inputs, iterations, baseline.as_ref(), threshold)?;
+            if let Some(path) = save {
+                let previous = if path.exists() { Some(load_baseline(&path)?) } else { None };
+                save_baseline(&path, previous, timings)?;
+            }
+            if regressions > 0 {
+                bail!("{regressions} regressed stages");
+            }
+            Ok(())
+        }
+    }
+}
+
+// days of the selector, or all of them
+fn selector_days(selector: Option<Selector>) -> Result<Vec<&'static Day>> {
+    match selector {
+        Some(selector) => selector.days(),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
+
+fn run(inputs: &Inputs, selector: Selector, params: &[(String, String)], input: Option<&Path>) -> Result<()> {
+    match selector {
+        Selector::Day(..) => {
+            let day = selector.days()?[0];
+            let input = match input {
+                Some(path) if path == Path::new("-") => InputSource::Stdin.read()?,
+                Some(path) => fs::read_to_string(path).with_context(|| format!("reading input {}", path.display()))?,
+                None => inputs.resolve(day.year, day.day, &day.dir())?.read()?,
+            };
+            solve(day, params, &input).with_context(|| format!("running {}", day.name()))
+        }
+        Selector::Year(_) => {
+            if !params.is_empty() || input.is_some() {
+                bail!("parameters and --input apply to a single day, not to every day of {selector}");
+            }
+            for day in selector.days()? {
+                println!("== {} ==", day.name());
+                let Some(source) = inputs.find(day.year, day.day, &day.dir()) else {
+                    println!("skipped, no input at {}", inputs.cache_path(day.year, day.day).display());
+                    continue;
+                };
+                solve(day, &[], &source.read()?).with_context(|| format!("running {}", day.name()))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn fetch(inputs: &Inputs, selector: Selector, base_url: &str) -> Result<()> {
+    let fetcher = Fetcher::new(base_url, &session_token()?);
+    for day in selector.days()? {
+        match fetcher.fetch(inputs, day.year, day.day)? {
+            FetchOutcome::Downloaded(path) => println!("{}: downloaded to {}", day.name(), path.display()),
+            FetchOutcome::AlreadyCached(path) => println!("{}: already at {}", day.name(), path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn solve(day: &Day, params: &[(String, String)], input: &str) -> Result<()> {
+    let (part1, part2) = day.solve_with(params, input)?;
+    print_answer(1, &part1);
+    print_answer(2, &part2);
+    Ok(())
+}
+
+fn print_answer(part: u8, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("part {part}:\n{answer}");
+    } else {
+        println!("part {part}: {answer}");
+    }
+}
+
+fn default_inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+}
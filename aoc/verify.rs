@@ -0,0 +1,166 @@
+use crate::days::Day;
+use anyhow::Result;
+use itertools::Itertools;
+use lib::answers::{AnswerStore, DayAnswers};
+use lib::input::Inputs;
+use lib::solution::Answer;
+use std::path::{Path, PathBuf};
+
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String, actual: String },
+    Unrecorded(String),
+}
+
+pub enum Report {
+    // no local input to run against
+    Skipped,
+    Checked(Verdict, Verdict),
+    // reading the input or solving failed, the other days are still checked
+    Failed(anyhow::Error),
+}
+
+impl Report {
+    pub fn is_wrong(&self) -> bool {
+        match self {
+            Report::Skipped | Report::Failed(_) => false,
+            Report::Checked(part1, part2) => {
+                matches!(part1, Verdict::Wrong { .. }) || matches!(part2, Verdict::Wrong { .. })
+            }
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Report::Failed(_))
+    }
+}
+
+fn check(expected: Option<&String>, actual: &Answer) -> Verdict {
+    let actual = actual.to_string();
+    match expected {
+        None => Verdict::Unrecorded(actual),
+        Some(expected) if *expected == actual => Verdict::Correct,
+        Some(expected) => Verdict::Wrong {
+            expected: expected.clone(),
+            actual,
+        },
+    }
+}
+
+// runs the days against their inputs and compares with the recorded answers, optionally recording the new ones
+pub fn verify<'a>(
+    days: impl IntoIterator<Item = &'a Day>,
+    inputs: &Inputs,
+    store: &AnswerStore,
+    record: bool,
+    mut on_report: impl FnMut(&Day, &Report),
+) -> Result<()> {
+    for (year, days) in &days.into_iter().chunk_by(|day| day.year) {
+        let mut answers = store.load(year)?;
+        let mut changed = false;
+        for day in days {
+            let report = match inputs.find(day.year, day.day, &day.dir()) {
+                None => Report::Skipped,
+                Some(source) => match source.read().map_err(anyhow::Error::from).and_then(|input| day.solve(&input)) {
+                    Err(err) => Report::Failed(err),
+                    Ok((part1, part2)) => {
+                        let recorded = answers.get(&day.day);
+                        let report = Report::Checked(
+                            check(recorded.and_then(|r| r.part1.as_ref()), &part1),
+                            check(recorded.and_then(|r| r.part2.as_ref()), &part2),
+                        );
+                        let new = DayAnswers::new(&part1, &part2);
+                        if record && recorded != Some(&new) {
+                            answers.insert(day.day, new);
+                            changed = true;
+                        }
+                        report
+                    }
+                },
+            };
+            on_report(day, &report);
+        }
+        if changed {
+            store.save(year, &answers)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_report(day: &Day, report: &Report) {
+    match report {
+        Report::Skipped => println!("{}: skipped, no input", day.name()),
+        Report::Failed(err) => println!("{}: failed, {err:#}", day.name()),
+        Report::Checked(Verdict::Correct, Verdict::Correct) => println!("{}: ok", day.name()),
+        Report::Checked(part1, part2) => {
+            for (part, verdict) in [(1, part1), (2, part2)] {
+                match verdict {
+                    Verdict::Correct => println!("{} part {part}: ok", day.name()),
+                    Verdict::Wrong { expected, actual } => {
+                        println!("{} part {part}: expected {expected}, got {actual}", day.name())
+                    }
+                    Verdict::Unrecorded(actual) => {
+                        println!("{} part {part}: no recorded answer, got {actual}", day.name())
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn default_answers_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::DAYS;
+    use crate::default_inputs_dir;
+    use crate::verify::{default_answers_dir, verify, Report};
+    use lib::answers::AnswerStore;
+    use lib::input::Inputs;
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    #[ignore = "runs every day with an input, use cargo test --release -- --ignored"]
+    fn recorded_answers_match() {
+        let inputs_dir = std::env::var_os("AOC_INPUTS").map_or_else(default_inputs_dir, PathBuf::from);
+        let inputs = Inputs::new(inputs_dir);
+        let store = AnswerStore::new(default_answers_dir());
+
+        let mut wrong = vec![];
+        verify(DAYS, &inputs, &store, false, |day, report| {
+            if report.is_wrong() {
+                wrong.push(day.name());
+            }
+        })
+        .unwrap();
+
+        assert!(wrong.is_empty(), "wrong answers in {}", wrong.join(", "));
+    }
+
+    #[test]
+    fn failed_day_does_not_stop_recording() {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+        fs::write(dir.join("inputs/2022/01.txt"), "not calories\n").unwrap();
+        fs::write(dir.join("inputs/2022/04.txt"), "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n").unwrap();
+        let store = AnswerStore::new(dir.join("answers"));
+        let days = DAYS.iter().filter(|day| day.year == 2022 && day.day <= 4);
+
+        let mut reports = vec![];
+        verify(days, &Inputs::new(dir.join("inputs")), &store, true, |day, report| {
+            reports.push((day.day, report.is_failed(), matches!(report, Report::Checked(..))));
+        })
+        .unwrap();
+
+        assert_eq!(reports, [(1, true, false), (4, false, true)]);
+        let recorded = store.load(2022).unwrap();
+        assert!(!recorded.contains_key(&1));
+        assert_eq!(recorded[&4].part1.as_deref(), Some("2"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
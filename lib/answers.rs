@@ -0,0 +1,103 @@
+use crate::solution::Answer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("reading {}: {source}", .path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("parsing {}: {source}", .path.display())]
+    Parse { path: PathBuf, source: toml::de::Error },
+    #[error("writing {}: {source}", .path.display())]
+    Write { path: PathBuf, source: io::Error },
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn new(part1: &Answer, part2: &Answer) -> Self {
+        DayAnswers {
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+        }
+    }
+}
+
+// recorded answers of one year, by day
+pub type YearAnswers = BTreeMap<u8, DayAnswers>;
+
+// answers of every year, stored as one YYYY.toml file per year
+pub struct AnswerStore {
+    dir: PathBuf,
+}
+
+impl AnswerStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        AnswerStore { dir: dir.into() }
+    }
+
+    pub fn path(&self, year: u16) -> PathBuf {
+        self.dir.join(format!("{year}.toml"))
+    }
+
+    // missing file means nothing was recorded yet
+    pub fn load(&self, year: u16) -> Result<YearAnswers, AnswersError> {
+        let path = self.path(year);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Default::default()),
+            Err(source) => return Err(AnswersError::Read { path, source }),
+        };
+        toml::from_str(&content).map_err(|source| AnswersError::Parse { path, source })
+    }
+
+    pub fn save(&self, year: u16, answers: &YearAnswers) -> Result<(), AnswersError> {
+        let path = self.path(year);
+        let content = toml::to_string(answers).expect("answers are always serializable");
+        write(&path, &content).map_err(|source| AnswersError::Write { path, source })
+    }
+}
+
+fn write(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{AnswerStore, DayAnswers};
+    use crate::solution::Answer;
+    use std::fs;
+
+    #[test]
+    fn saved_answers_load_back() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let store = AnswerStore::new(&dir);
+        assert!(store.load(2022).unwrap().is_empty());
+
+        let mut answers = store.load(2022).unwrap();
+        answers.insert(10, DayAnswers::new(&Answer::from(13140), &Answer::from("##..\n..##")));
+        answers.insert(
+            25,
+            DayAnswers {
+                part1: Some("2=-1=0".to_string()),
+                part2: None,
+            },
+        );
+        store.save(2022, &answers).unwrap();
+
+        assert_eq!(store.load(2022).unwrap(), answers);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
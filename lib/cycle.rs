@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// states of a stream from prefix on repeat every period
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // earliest iteration with the same state as iteration n
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+// Floyd's tortoise and hare, in constant memory but stepping every state several times.
+// Never returns if the states never repeat.
+pub fn floyd<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // the hare is now a multiple of the period ahead, meeting the tortoise first at the cycle start
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// Brent's algorithm, in constant memory, with fewer steps than floyd.
+// Never returns if the states never repeat.
+pub fn brent<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    // the tortoise waits at powers of two for the hare to come around
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// first repetition in the stream, remembering every state, none if the stream ends before
+pub fn detect<S: Hash + Eq>(states: impl IntoIterator<Item = S>) -> Option<Cycle> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    for (i, state) in states.into_iter().enumerate() {
+        if let Some(first) = seen.insert(state, i) {
+            return Some(Cycle {
+                prefix: first,
+                period: i - first,
+            });
+        }
+    }
+    None
+}
+
+// state after stepping n times from start, stepping no further than the first repetition
+pub fn nth<S, F>(start: S, mut step: F, n: usize) -> S
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut history = vec![start.clone()];
+    let mut seen: HashMap<S, usize> = HashMap::from([(start, 0)]);
+    while history.len() <= n {
+        let next = step(&history[history.len() - 1]);
+        if let Some(&first) = seen.get(&next) {
+            let cycle = Cycle {
+                prefix: first,
+                period: history.len() - first,
+            };
+            return history.swap_remove(cycle.equivalent(n));
+        }
+        seen.insert(next.clone(), history.len());
+        history.push(next);
+    }
+    history.swap_remove(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::{brent, detect, floyd, nth, Cycle};
+    use rstest::rstest;
+
+    // rho shaped: 0 1 2 3 4 5 6 then back to 3
+    fn step(&x: &u32) -> u32 {
+        if x < 6 {
+            x + 1
+        } else {
+            3
+        }
+    }
+
+    #[rstest]
+    #[case(floyd(0, step))]
+    #[case(brent(0, step))]
+    #[case(detect(std::iter::successors(Some(0), |x| Some(step(x)))).unwrap())]
+    fn finds_prefix_and_period(#[case] cycle: Cycle) {
+        assert_eq!(cycle, Cycle { prefix: 3, period: 4 });
+    }
+
+    #[rstest]
+    #[case(floyd(5, |&x: &u64| x * x % 17))]
+    #[case(brent(5, |&x: &u64| x * x % 17))]
+    fn agree_on_longer_streams(#[case] cycle: Cycle) {
+        // 5 8 13 16 1 1
+        assert_eq!(cycle, Cycle { prefix: 4, period: 1 });
+    }
+
+    #[test]
+    fn stream_without_repetition() {
+        assert_eq!(detect(0..100), None);
+    }
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(6, 6)]
+    #[case(7, 3)]
+    #[case(1_000_000_000, 4)]
+    fn state_at_iteration(#[case] n: usize, #[case] expected: u32) {
+        assert_eq!(nth(0, step, n), expected);
+        assert_eq!(Cycle { prefix: 3, period: 4 }.equivalent(n), expected as usize);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::input::Inputs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, io, thread};
+use thiserror::Error;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const USER_AGENT: &str = concat!(
+    "github.com/Fazt01/advent_of_code input fetcher ",
+    env!("CARGO_PKG_VERSION"),
+);
+// minimal delay between two requests, also across runs
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+// marker in the cache directory, its modification time is the time of the last request
+const LAST_REQUEST_MARKER: &str = ".last-request";
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error("no session token, set {SESSION_ENV} or write it to {}", .0.display())]
+    NoSession(PathBuf),
+    #[error("requesting {url}: server responded with {status}: {body}")]
+    Status { url: String, status: u16, body: String },
+    #[error("requesting {url}: {source}")]
+    Request { url: String, source: Box<ureq::Error> },
+    #[error("writing {}: {source}", .path.display())]
+    Write { path: PathBuf, source: io::Error },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FetchOutcome {
+    Downloaded(PathBuf),
+    AlreadyCached(PathBuf),
+}
+
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+
+    // downloads the input into the cache, unless it is already there
+    pub fn fetch(&self, inputs: &Inputs, year: u16, day: u8) -> Result<FetchOutcome, FetchError> {
+        let path = inputs.cache_path(year, day);
+        if path.exists() {
+            return Ok(FetchOutcome::AlreadyCached(path));
+        }
+
+        self.wait_for_turn(inputs.cache_dir())?;
+
+        let url = self.input_url(year, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        let body = match response {
+            Ok(response) => response.into_string(),
+            Err(ureq::Error::Status(status, response)) => {
+                return Err(FetchError::Status {
+                    url,
+                    status,
+                    body: response.into_string().unwrap_or_default().trim().to_string(),
+                })
+            }
+            Err(err) => return Err(FetchError::Request { url, source: Box::new(err) }),
+        }
+        .map_err(|err| FetchError::Request {
+            url,
+            source: Box::new(err.into()),
+        })?;
+
+        // write next to the target first, so that an interrupted download is never taken as cached
+        let partial = path.with_extension("part");
+        let write_error = |source| FetchError::Write {
+            path: path.clone(),
+            source,
+        };
+        fs::create_dir_all(path.parent().unwrap()).map_err(write_error)?;
+        fs::write(&partial, body).map_err(write_error)?;
+        fs::rename(&partial, &path).map_err(write_error)?;
+
+        Ok(FetchOutcome::Downloaded(path))
+    }
+
+    fn wait_for_turn(&self, cache_dir: &Path) -> Result<(), FetchError> {
+        let marker = cache_dir.join(LAST_REQUEST_MARKER);
+        let last_request = fs::metadata(&marker).and_then(|metadata| metadata.modified());
+        if let Ok(elapsed) = last_request.map(|time| time.elapsed().unwrap_or_default()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let write_error = |source| FetchError::Write {
+            path: marker.clone(),
+            source,
+        };
+        fs::create_dir_all(cache_dir).map_err(write_error)?;
+        fs::File::create(&marker)
+            .and_then(|file| file.set_modified(SystemTime::now()))
+            .map_err(write_error)
+    }
+}
+
+// session token from the environment, or from the config file
+pub fn session_token() -> Result<String, FetchError> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+    let path = session_config_path();
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(FetchError::NoSession(path)),
+    }
+}
+
+// $XDG_CONFIG_HOME/aoc/session, defaulting to ~/.config/aoc/session
+pub fn session_config_path() -> PathBuf {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .unwrap_or_default();
+    config_dir.join("aoc").join("session")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::{FetchError, FetchOutcome, Fetcher, USER_AGENT};
+    use crate::input::Inputs;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    // answers every request with the given status and body, sending the request head back to the test
+    fn stub_server(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let head: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                if sender.send(head).is_err() {
+                    break;
+                }
+            }
+        });
+        (url, receiver)
+    }
+
+    #[test]
+    fn downloads_into_cache_once() {
+        let (url, requests) = stub_server("200 OK", "1,2,3\n");
+        let inputs = Inputs::new(temp_dir("once"));
+        let fetcher = Fetcher::new(&url, "secret\n").min_interval(Duration::ZERO);
+
+        let path = inputs.cache_path(2024, 16);
+        assert_eq!(fetcher.fetch(&inputs, 2024, 16).unwrap(), FetchOutcome::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+
+        let head = requests.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(head[0], "GET /2024/day/16/input HTTP/1.1");
+        assert!(head.iter().any(|line| line.eq_ignore_ascii_case("cookie: session=secret")));
+        assert!(head.iter().any(|line| line.eq_ignore_ascii_case(&format!("user-agent: {USER_AGENT}"))));
+
+        assert_eq!(fetcher.fetch(&inputs, 2024, 16).unwrap(), FetchOutcome::AlreadyCached(path));
+        assert!(requests.recv_timeout(Duration::from_millis(100)).is_err());
+
+        fs::remove_dir_all(inputs.cache_dir()).unwrap();
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let (url, _requests) = stub_server("400 Bad Request", "Please log in.");
+        let inputs = Inputs::new(temp_dir("failed"));
+        let fetcher = Fetcher::new(&url, "expired").min_interval(Duration::ZERO);
+
+        let err = fetcher.fetch(&inputs, 2023, 5).unwrap_err();
+        assert!(matches!(err, FetchError::Status { status: 400, .. }));
+        assert!(err.to_string().contains("Please log in."));
+        assert!(!inputs.cache_path(2023, 5).exists());
+
+        let _ = fs::remove_dir_all(inputs.cache_dir());
+    }
+}
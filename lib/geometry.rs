@@ -0,0 +1,5 @@
+mod polygon;
+mod rect;
+
+pub use polygon::Polygon;
+pub use rect::Rect;
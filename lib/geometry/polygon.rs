@@ -0,0 +1,209 @@
+use crate::grid::{Coord, Offset};
+use crate::math;
+
+// closed polygon with lattice vertices, the last one connected back to the first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Coord>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Coord>) -> Polygon {
+        Polygon { vertices }
+    }
+
+    // vertices of a walk from 0,0, every step going its length in its direction, ending back at the start
+    pub fn from_walk(steps: impl IntoIterator<Item = (Offset, i64)>) -> Polygon {
+        let mut vertices = vec![];
+        let mut current = Coord::default();
+        for (direction, length) in steps {
+            vertices.push(current);
+            current = current + direction * length;
+        }
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Coord] {
+        &self.vertices
+    }
+
+    // every vertex with the next one
+    pub fn edges(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&from, &to)| (from, to))
+    }
+
+    // twice the area, so that it is whole, positive for vertices going clockwise with y pointing down,
+    // none on overflow
+    pub fn double_signed_area(&self) -> Option<i64> {
+        self.edges().try_fold(0i64, |area, (a, b)| {
+            area.checked_add(a.x.checked_mul(b.y)?.checked_sub(b.x.checked_mul(a.y)?)?)
+        })
+    }
+
+    // lattice points on the edges, none on overflow
+    pub fn boundary_points(&self) -> Option<i64> {
+        self.edges().try_fold(0i64, |points, (a, b)| {
+            points.checked_add(math::gcd(b.x.checked_sub(a.x)?, b.y.checked_sub(a.y)?)?)
+        })
+    }
+
+    // lattice points strictly inside, by Pick's theorem: A = I + B/2 - 1
+    pub fn interior_points(&self) -> Option<i64> {
+        let area = self.double_signed_area()?.checked_abs()?;
+        Some(area.checked_sub(self.boundary_points()?)?.checked_add(2)? / 2)
+    }
+
+    // lattice points inside or on the edges, as cells of a grid the polygon is drawn through
+    pub fn lattice_points(&self) -> Option<i64> {
+        self.interior_points()?.checked_add(self.boundary_points()?)
+    }
+
+    // inside or on an edge, none on overflow
+    pub fn contains(&self, point: Coord) -> Option<bool> {
+        self.contains_doubled(doubled(point)?)
+    }
+
+    // whether the rectangle with the opposite corners is inside or on the edges, none for polygons
+    // with edges that are not horizontal or vertical, or on overflow
+    pub fn contains_rect(&self, corner: Coord, opposite: Coord) -> Option<bool> {
+        let min = Coord {
+            x: corner.x.min(opposite.x),
+            y: corner.y.min(opposite.y),
+        };
+        let max = Coord {
+            x: corner.x.max(opposite.x),
+            y: corner.y.max(opposite.y),
+        };
+        let crosses = |at: i64, from: i64, to: i64, low: i64, high: i64, min_along: i64, max_along: i64| {
+            low < at && at < high && from.max(to) > min_along && from.min(to) < max_along
+        };
+        for (from, to) in self.edges() {
+            let inside = if from.x == to.x {
+                crosses(from.x, from.y, to.y, min.x, max.x, min.y, max.y)
+            } else if from.y == to.y {
+                crosses(from.y, from.x, to.x, min.y, max.y, min.x, max.x)
+            } else {
+                return None;
+            };
+            // an edge through the rectangle leaves a part of it outside
+            if inside {
+                return Some(false);
+            }
+        }
+        // with no edge through it, the whole rectangle is on the side of its centre
+        self.contains_doubled(Coord {
+            x: min.x.checked_add(max.x)?,
+            y: min.y.checked_add(max.y)?,
+        })
+    }
+
+    // containment of a point with coordinates doubled, so that centres between lattice points are whole
+    fn contains_doubled(&self, point: Coord) -> Option<bool> {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (a, b) = (doubled(a)?, doubled(b)?);
+            // the edge is collinear with the point when both products of the cross product are equal
+            let edge_times_point = b.x.checked_sub(a.x)?.checked_mul(point.y.checked_sub(a.y)?)?;
+            let point_times_edge = point.x.checked_sub(a.x)?.checked_mul(b.y.checked_sub(a.y)?)?;
+            let within = a.x.min(b.x) <= point.x && point.x <= a.x.max(b.x) && a.y.min(b.y) <= point.y && point.y <= a.y.max(b.y);
+            if edge_times_point == point_times_edge && within {
+                return Some(true);
+            }
+            // ray to the left, counting edges crossing its row, each including only its lower end
+            if (a.y > point.y) != (b.y > point.y) {
+                let left = point_times_edge > edge_times_point;
+                if left == (b.y > a.y) {
+                    inside = !inside;
+                }
+            }
+        }
+        Some(inside)
+    }
+}
+
+fn doubled(coord: Coord) -> Option<Coord> {
+    Some(Coord {
+        x: coord.x.checked_mul(2)?,
+        y: coord.y.checked_mul(2)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Polygon;
+    use crate::grid::{coord, Coord, OFFSET_DOWN, OFFSET_LEFT, OFFSET_RIGHT, OFFSET_UP};
+    use rstest::rstest;
+
+    fn example() -> Polygon {
+        Polygon::new(vec![coord(7, 1), coord(11, 1), coord(11, 7), coord(9, 7), coord(9, 5), coord(2, 5), coord(2, 3), coord(7, 3)])
+    }
+
+    #[test]
+    fn counts_area_and_lattice_points() {
+        // 4x3 rectangle
+        let rect = Polygon::from_walk([(OFFSET_RIGHT, 4), (OFFSET_DOWN, 3), (OFFSET_LEFT, 4), (OFFSET_UP, 3)]);
+        assert_eq!(rect.double_signed_area(), Some(24));
+        assert_eq!(rect.boundary_points(), Some(14));
+        assert_eq!(rect.interior_points(), Some(6));
+        assert_eq!(rect.lattice_points(), Some(20));
+
+        let reversed = Polygon::new(rect.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.double_signed_area(), Some(-24));
+        assert_eq!(reversed.lattice_points(), Some(20));
+
+        let triangle = Polygon::new(vec![coord(0, 0), coord(4, 0), coord(0, 3)]);
+        assert_eq!((triangle.double_signed_area(), triangle.boundary_points(), triangle.interior_points()), (Some(12), Some(8), Some(3)));
+
+        let huge = Polygon::new(vec![coord(i64::MIN, 0), coord(i64::MAX, 0)]);
+        assert_eq!(huge.boundary_points(), None);
+        assert_eq!(huge.contains(coord(0, 0)), None);
+    }
+
+    #[test]
+    fn overflows_to_none() {
+        // the area fits, but one of the products summed for it does not
+        let wide = Polygon::new(vec![coord(0, 0), coord(1 << 62, 0), coord(1 << 62, 4), coord(0, 4)]);
+        assert_eq!(wide.double_signed_area(), None);
+        assert_eq!(wide.interior_points(), None);
+        assert_eq!(wide.lattice_points(), None);
+
+        // the area fits, but the longer boundary does not
+        let thin = Polygon::new(vec![coord(0, 0), coord(i64::MAX / 2, 0), coord(i64::MAX / 2, 1), coord(0, 1)]);
+        assert_eq!(thin.double_signed_area(), Some(i64::MAX - 1));
+        assert_eq!(thin.boundary_points(), None);
+        assert_eq!(thin.interior_points(), None);
+    }
+
+    #[rstest]
+    #[case(coord(9, 2), true)]
+    #[case(coord(2, 4), true)]
+    #[case(coord(11, 7), true)]
+    #[case(coord(8, 6), false)]
+    #[case(coord(3, 2), false)]
+    #[case(coord(12, 4), false)]
+    fn contains_points(#[case] point: Coord, #[case] expected: bool) {
+        assert_eq!(example().contains(point), Some(expected));
+    }
+
+    #[rstest]
+    #[case(coord(9, 5), coord(2, 3), true)]
+    #[case(coord(7, 1), coord(11, 5), true)]
+    #[case(coord(7, 1), coord(11, 7), false)]
+    #[case(coord(11, 1), coord(2, 5), false)]
+    #[case(coord(2, 5), coord(11, 7), false)]
+    #[case(coord(2, 3), coord(7, 1), false)]
+    #[case(coord(2, 3), coord(2, 5), true)]
+    fn contains_rectangles(#[case] corner: Coord, #[case] opposite: Coord, #[case] expected: bool) {
+        assert_eq!(example().contains_rect(corner, opposite), Some(expected));
+    }
+
+    #[test]
+    fn contains_rect_needs_straight_edges() {
+        let triangle = Polygon::new(vec![coord(0, 0), coord(4, 0), coord(0, 4)]);
+        assert_eq!(triangle.contains(coord(1, 1)), Some(true));
+        assert_eq!(triangle.contains_rect(coord(0, 0), coord(1, 1)), None);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::grid::{Coord, Grid};
+
+// axis-aligned rectangle between two inclusive corners
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl Rect {
+    // rectangle with the two opposite corners, in any order
+    pub fn new(corner: Coord, opposite: Coord) -> Rect {
+        Rect {
+            min: Coord {
+                x: corner.x.min(opposite.x),
+                y: corner.y.min(opposite.y),
+            },
+            max: Coord {
+                x: corner.x.max(opposite.x),
+                y: corner.y.max(opposite.y),
+            },
+        }
+    }
+
+    // smallest rectangle around all the points, none for no points
+    pub fn from_points(points: impl IntoIterator<Item = Coord>) -> Option<Rect> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rect::new(first, first), Rect::include))
+    }
+
+    // cells of a grid of the size, from 0,0, none for an empty size
+    pub fn from_size(columns: usize, rows: usize) -> Option<Rect> {
+        if columns == 0 || rows == 0 {
+            return None;
+        }
+        Some(Rect::new(
+            Coord::default(),
+            Coord {
+                x: columns as i64 - 1,
+                y: rows as i64 - 1,
+            },
+        ))
+    }
+
+    // columns and rows of a grid covering the rectangle's cells
+    pub fn size(&self) -> (usize, usize) {
+        ((self.width() + 1) as usize, (self.height() + 1) as usize)
+    }
+
+    // grown to cover the point
+    pub fn include(self, point: Coord) -> Rect {
+        Rect {
+            min: Coord {
+                x: self.min.x.min(point.x),
+                y: self.min.y.min(point.y),
+            },
+            max: Coord {
+                x: self.max.x.max(point.x),
+                y: self.max.y.max(point.y),
+            },
+        }
+    }
+
+    pub fn contains(&self, point: Coord) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x && self.min.y <= point.y && point.y <= self.max.y
+    }
+
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    // points of both, none if they do not overlap
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Coord {
+            x: self.min.x.max(other.min.x),
+            y: self.min.y.max(other.min.y),
+        };
+        let max = Coord {
+            x: self.max.x.min(other.max.x),
+            y: self.max.y.min(other.max.y),
+        };
+        (min.x <= max.x && min.y <= max.y).then_some(Rect { min, max })
+    }
+
+    // distance between the corners, in x
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x
+    }
+
+    // distance between the corners, in y
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y
+    }
+
+    // area between the corners as points, 0 for a line
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    // number of lattice points, or cells, with the corners included
+    pub fn lattice_area(&self) -> i64 {
+        (self.width() + 1) * (self.height() + 1)
+    }
+
+    // on the outermost rows or columns
+    pub fn is_border(&self, point: Coord) -> bool {
+        self.contains(point)
+            && (point.x == self.min.x || point.x == self.max.x || point.y == self.min.y || point.y == self.max.y)
+    }
+
+    // top left, top right, bottom left and bottom right quarters of the cells, leaving out the middle
+    // row and column of odd sizes, none if a side has fewer than two cells
+    pub fn quadrants(&self) -> Option<[Rect; 4]> {
+        let (columns, rows) = self.size();
+        if columns < 2 || rows < 2 {
+            return None;
+        }
+        let (half_x, half_y) = ((columns / 2) as i64, (rows / 2) as i64);
+        let left = (self.min.x, self.min.x + half_x - 1);
+        let right = (self.max.x - half_x + 1, self.max.x);
+        let top = (self.min.y, self.min.y + half_y - 1);
+        let bottom = (self.max.y - half_y + 1, self.max.y);
+        let quadrant = |(min_x, max_x): (i64, i64), (min_y, max_y): (i64, i64)| Rect {
+            min: Coord { x: min_x, y: min_y },
+            max: Coord { x: max_x, y: max_y },
+        };
+        Some([quadrant(left, top), quadrant(right, top), quadrant(left, bottom), quadrant(right, bottom)])
+    }
+}
+
+impl<T> Grid<T> {
+    // all cells, none for an empty grid
+    pub fn rect(&self) -> Option<Rect> {
+        Rect::from_size(self.columns(), self.rows())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Rect;
+    use crate::grid::{coord, Grid};
+
+    #[test]
+    fn grows_around_points() {
+        let rect = Rect::from_points([coord(500, 0), coord(498, 9), coord(503, -2)]).unwrap();
+        assert_eq!(rect, Rect::new(coord(503, 9), coord(498, -2)));
+        assert_eq!((rect.width(), rect.height(), rect.area(), rect.lattice_area()), (5, 11, 55, 72));
+        assert_eq!(rect.size(), (6, 12));
+        assert!(rect.contains(coord(498, 9)) && !rect.contains(coord(497, 9)));
+        assert!(rect.is_border(coord(500, -2)) && !rect.is_border(coord(500, 0)));
+        assert_eq!(Rect::from_points([]), None);
+    }
+
+    #[test]
+    fn intersects() {
+        let a = Rect::new(coord(0, 0), coord(5, 5));
+        assert_eq!(a.intersection(&Rect::new(coord(3, -2), coord(8, 4))), Some(Rect::new(coord(3, 0), coord(5, 4))));
+        assert_eq!(a.intersection(&Rect::new(coord(5, 5), coord(6, 6))), Some(Rect::new(coord(5, 5), coord(5, 5))));
+        assert_eq!(a.intersection(&Rect::new(coord(6, 0), coord(8, 4))), None);
+        assert!(a.contains_rect(&Rect::new(coord(1, 1), coord(5, 2))));
+    }
+
+    #[test]
+    fn splits_into_quadrants() {
+        let [top_left, top_right, bottom_left, bottom_right] = Rect::from_size(11, 7).unwrap().quadrants().unwrap();
+        assert_eq!(top_left, Rect::new(coord(0, 0), coord(4, 2)));
+        assert_eq!(top_right, Rect::new(coord(6, 0), coord(10, 2)));
+        assert_eq!(bottom_left, Rect::new(coord(0, 4), coord(4, 6)));
+        assert_eq!(bottom_right, Rect::new(coord(6, 4), coord(10, 6)));
+
+        let even = Rect::from_size(4, 2).unwrap().quadrants().unwrap();
+        assert_eq!(even[1], Rect::new(coord(2, 0), coord(3, 0)));
+        assert_eq!(Rect::from_size(1, 5).unwrap().quadrants(), None);
+    }
+
+    #[test]
+    fn covers_grids() {
+        let grid: Grid<u8> = Grid::new(3, 2);
+        let rect = grid.rect().unwrap();
+        assert_eq!(rect.size(), (grid.columns(), grid.rows()));
+        assert!(grid.iter().all(|(coord, _)| rect.contains(coord)));
+        assert_eq!(Grid::<u8>::new(0, 0).rect(), None);
+    }
+}
@@ -1,8 +1,26 @@
 use std::convert::Infallible;
-use std::iter;
-use std::ops::{Add, Index, IndexMut, Neg, Sub};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
 use thiserror::Error;
 
+mod automaton;
+mod compression;
+mod distance;
+mod prefix_sums;
+mod region;
+mod sparse;
+mod tile;
+mod transform;
+mod wrapping;
+
+pub use automaton::{Automaton, Neighbourhood};
+pub use compression::Compression;
+pub use prefix_sums::PrefixSums;
+pub use region::{Connectivity, Region, Regions};
+pub use sparse::SparseGrid;
+pub use tile::{GridTile, ParseGridError};
+pub use wrapping::Wrapping;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     points: Vec<T>,
     rows: usize,
@@ -109,7 +127,7 @@ impl<T> Grid<T> {
     pub fn new_sized_as<U>(src: &Grid<U>) -> Grid<T>
     where T: Default + Clone {
         Grid::<T>{
-            points: iter::repeat(T::default()).take(src.columns * src.rows).collect(),
+            points: vec![T::default(); src.columns * src.rows],
             rows: src.rows,
             columns: src.columns,
         }
@@ -196,7 +214,7 @@ impl<T: Clone> Grid<T>{
     pub fn new_with_values(columns: usize, rows: usize, value: T) -> Grid<T>
     {
         Grid::<T>{
-            points: iter::repeat(value).take(columns * rows).collect(),
+            points: vec![value; columns * rows],
             rows,
             columns,
         }
@@ -301,6 +319,12 @@ pub struct Coord {
     pub y: i64,
 }
 
+// shorthand for coordinates in tests
+#[cfg(test)]
+pub(crate) fn coord(x: i64, y: i64) -> Coord {
+    Coord { x, y }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Ord, PartialOrd)]
 pub struct Offset {
     pub x: i64,
@@ -335,6 +359,17 @@ impl Add for Offset {
     }
 }
 
+impl Mul<i64> for Offset {
+    type Output = Offset;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Offset {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
 impl Neg for Offset {
     type Output = Offset;
 
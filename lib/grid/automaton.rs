@@ -0,0 +1,153 @@
+use crate::grid::{Connectivity, Coord, Grid, Offset};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// grid where every cell changes at once by a rule over the cell and its neighbours
+pub struct Automaton<T, F> {
+    current: Grid<T>,
+    // written by a step, then swapped with current
+    next: Grid<T>,
+    connectivity: Connectivity,
+    rule: F,
+    generation: usize,
+}
+
+// cells around the one a rule decides, those outside of the grid left out
+pub struct Neighbourhood<'a, T> {
+    grid: &'a Grid<T>,
+    coord: Coord,
+    offsets: &'static [Offset],
+}
+
+impl<'a, T> Neighbourhood<'a, T> {
+    pub fn coord(&self) -> Coord {
+        self.coord
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.offsets.iter().filter_map(|&offset| self.grid.get(self.coord + offset))
+    }
+
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.iter().filter(|&value| predicate(value)).count()
+    }
+}
+
+impl<T, F> Automaton<T, F>
+where
+    T: Clone + PartialEq,
+    F: FnMut(&T, Neighbourhood<'_, T>) -> T,
+{
+    pub fn new(grid: Grid<T>, connectivity: Connectivity, rule: F) -> Self {
+        Automaton {
+            next: grid.clone(),
+            current: grid,
+            connectivity,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    // steps taken so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // number of cells changed by the step
+    pub fn step(&mut self) -> usize {
+        let mut changed = 0;
+        for (coord, value) in self.current.iter() {
+            let neighbourhood = Neighbourhood {
+                grid: &self.current,
+                coord,
+                offsets: self.connectivity.offsets(),
+            };
+            let next = (self.rule)(value, neighbourhood);
+            if next != *value {
+                changed += 1;
+            }
+            self.next[coord] = next;
+        }
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    // changed cells of every step until one changes nothing, that step not included
+    pub fn run_until_stable(&mut self) -> Vec<usize> {
+        let mut changes = vec![];
+        loop {
+            match self.step() {
+                0 => return changes,
+                changed => changes.push(changed),
+            }
+        }
+    }
+
+    // as if stepping the given times, skipping whole periods once the grid repeats
+    pub fn run(&mut self, steps: usize)
+    where
+        T: Hash + Eq,
+    {
+        let target = self.generation + steps;
+        let mut seen: HashMap<Grid<T>, usize> = HashMap::new();
+        while self.generation < target {
+            if let Some(first) = seen.insert(self.current.clone(), self.generation) {
+                let period = self.generation - first;
+                self.generation += (target - self.generation) / period * period;
+                break;
+            }
+            self.step();
+        }
+        while self.generation < target {
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Automaton, Connectivity, Grid, Neighbourhood};
+
+    fn life(&alive: &bool, neighbours: Neighbourhood<'_, bool>) -> bool {
+        matches!((alive, neighbours.count(|&n| n)), (true, 2) | (_, 3))
+    }
+
+    #[test]
+    fn steps_every_cell_at_once() {
+        let mut automaton = Automaton::new(Grid::parse_str(".....\n..#..\n..#..\n..#..\n.....").unwrap(), Connectivity::Eight, life);
+        assert_eq!(automaton.step(), 4);
+        assert_eq!(automaton.grid(), &Grid::parse_str(".....\n.....\n.###.\n.....\n.....").unwrap());
+        assert_eq!(automaton.generation(), 1);
+    }
+
+    #[test]
+    fn runs_until_stable() {
+        // cells with fewer than two set neighbours are cleared
+        let mut automaton = Automaton::new(Grid::parse_str("##..\n##.#\n...#").unwrap(), Connectivity::Four, |&set, neighbours| {
+            set && neighbours.count(|&n| n) >= 2
+        });
+        assert_eq!(automaton.run_until_stable(), [2]);
+        assert_eq!(automaton.into_grid(), Grid::parse_str("##..\n##..\n....").unwrap());
+    }
+
+    #[test]
+    fn skips_repeating_periods() {
+        let blinker = Grid::parse_str(".....\n..#..\n..#..\n..#..\n.....").unwrap();
+        let mut automaton = Automaton::new(blinker.clone(), Connectivity::Eight, life);
+        automaton.run(1_000_000_001);
+        assert_eq!(automaton.generation(), 1_000_000_001);
+        assert_eq!(automaton.grid(), &Grid::parse_str(".....\n.....\n.###.\n.....\n.....").unwrap());
+
+        automaton.run(1);
+        assert_eq!(automaton.grid(), &blinker);
+    }
+}
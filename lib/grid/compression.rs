@@ -0,0 +1,156 @@
+use crate::geometry::Rect;
+use crate::grid::{Coord, Grid};
+
+// sparse coordinates mapped onto the cells of a dense grid: every distinct x and y of the points gets
+// a column or row of its own, and every gap between them one more, spanning the whole gap
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compression {
+    // first real coordinate of every column and row, with one past the last at the end
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+}
+
+// starts of the cells along one axis
+fn cell_starts(mut values: Vec<i64>) -> Vec<i64> {
+    values.sort_unstable();
+    values.dedup();
+    let mut starts = vec![];
+    for (i, &value) in values.iter().enumerate() {
+        starts.push(value);
+        if values.get(i + 1).is_some_and(|&next| next > value + 1) {
+            starts.push(value + 1);
+        }
+    }
+    if let Some(&last) = values.last() {
+        starts.push(last + 1);
+    }
+    starts
+}
+
+// cell along one axis the real coordinate is in
+fn cell_index(starts: &[i64], value: i64) -> Option<usize> {
+    let after = starts.partition_point(|&start| start <= value);
+    (after > 0 && after < starts.len()).then(|| after - 1)
+}
+
+impl Compression {
+    pub fn new(points: impl IntoIterator<Item = Coord>) -> Compression {
+        let (xs, ys) = points.into_iter().map(|point| (point.x, point.y)).unzip();
+        Compression {
+            xs: cell_starts(xs),
+            ys: cell_starts(ys),
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.xs.len().saturating_sub(1)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.ys.len().saturating_sub(1)
+    }
+
+    // real columns in the column of cells
+    pub fn width(&self, column: usize) -> i64 {
+        self.xs[column + 1] - self.xs[column]
+    }
+
+    // real rows in the row of cells
+    pub fn height(&self, row: usize) -> i64 {
+        self.ys[row + 1] - self.ys[row]
+    }
+
+    // real lattice points covered by the cell
+    pub fn area(&self, cell: Coord) -> i64 {
+        self.width(cell.x as usize) * self.height(cell.y as usize)
+    }
+
+    // cell the real point is in, none outside the box around the points
+    pub fn cell(&self, point: Coord) -> Option<Coord> {
+        Some(Coord {
+            x: cell_index(&self.xs, point.x)? as i64,
+            y: cell_index(&self.ys, point.y)? as i64,
+        })
+    }
+
+    // real coordinates the cell stands for
+    pub fn real_rect(&self, cell: Coord) -> Rect {
+        let (x, y) = (cell.x as usize, cell.y as usize);
+        Rect::new(
+            Coord {
+                x: self.xs[x],
+                y: self.ys[y],
+            },
+            Coord {
+                x: self.xs[x + 1] - 1,
+                y: self.ys[y + 1] - 1,
+            },
+        )
+    }
+
+    // cells covering the real rectangle, none if a corner is outside the box around the points
+    pub fn compress_rect(&self, rect: Rect) -> Option<Rect> {
+        Some(Rect::new(self.cell(rect.min)?, self.cell(rect.max)?))
+    }
+
+    // grid of the cells, with the same value everywhere
+    pub fn grid<T: Clone>(&self, value: T) -> Grid<T> {
+        Grid::new_with_values(self.columns(), self.rows(), value)
+    }
+
+    // grid of the real areas of the cells
+    pub fn areas(&self) -> Grid<i64> {
+        let mut areas = self.grid(0);
+        for y in 0..self.rows() {
+            for x in 0..self.columns() {
+                *areas.index_mut(x, y) = self.width(x) * self.height(y);
+            }
+        }
+        areas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Rect;
+    use crate::grid::{coord, Compression};
+
+    #[test]
+    fn gaps_become_single_cells() {
+        let compression = Compression::new([coord(2, 10), coord(7, 11), coord(3, 50)]);
+        // columns 2, 3, 4..=6, 7 and rows 10, 11, 12..=49, 50
+        assert_eq!((compression.columns(), compression.rows()), (4, 4));
+        let widths: Vec<_> = (0..4).map(|x| compression.width(x)).collect();
+        assert_eq!(widths, [1, 1, 3, 1]);
+        let heights: Vec<_> = (0..4).map(|y| compression.height(y)).collect();
+        assert_eq!(heights, [1, 1, 38, 1]);
+
+        assert_eq!(compression.cell(coord(5, 30)), Some(coord(2, 2)));
+        assert_eq!(compression.cell(coord(7, 50)), Some(coord(3, 3)));
+        assert_eq!(compression.cell(coord(8, 50)), None);
+        assert_eq!(compression.cell(coord(1, 10)), None);
+        assert_eq!(compression.real_rect(coord(2, 2)), Rect::new(coord(4, 12), coord(6, 49)));
+        assert_eq!(compression.area(coord(2, 2)), 114);
+    }
+
+    #[test]
+    fn areas_add_up_to_the_box() {
+        let points = [coord(-5, 0), coord(0, 3), coord(9, -4), coord(9, 3)];
+        let compression = Compression::new(points);
+        let total: i64 = compression.areas().iter().map(|(_, &area)| area).sum();
+        assert_eq!(total, Rect::from_points(points).unwrap().lattice_area());
+
+        let cells = compression.compress_rect(Rect::new(coord(-5, 3), coord(0, 0))).unwrap();
+        assert_eq!(cells, Rect::new(coord(0, 2), coord(2, 4)));
+        let real: i64 = compression.areas().iter().filter(|&(cell, _)| cells.contains(cell)).map(|(_, &area)| area).sum();
+        assert_eq!(real, 24);
+    }
+
+    #[test]
+    fn empty() {
+        let compression = Compression::new([]);
+        assert_eq!((compression.columns(), compression.rows()), (0, 0));
+        assert_eq!(compression.cell(coord(0, 0)), None);
+        assert_eq!(compression.grid('.').rows(), 0);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::grid::{Coord, Grid, Offset, DIRECTIONS_CARDINAL};
+use std::collections::VecDeque;
+
+impl Offset {
+    pub fn manhattan(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    // every offset of at most the manhattan distance, except the zero one
+    pub fn within_manhattan(distance: u64) -> Vec<Offset> {
+        let distance = distance as i64;
+        let mut offsets = vec![];
+        for y in -distance..=distance {
+            let max_x = distance - y.abs();
+            for x in -max_x..=max_x {
+                if (x, y) != (0, 0) {
+                    offsets.push(Offset { x, y });
+                }
+            }
+        }
+        offsets
+    }
+}
+
+impl<T> Grid<T> {
+    // steps from the nearest source to every cell reachable over passable cells, none for the others
+    pub fn distance_field(
+        &self,
+        sources: impl IntoIterator<Item = Coord>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.distance_field_by(sources, |_, to| passable(to))
+    }
+
+    // like distance_field, where stepping from one cell to its neighbour depends on both
+    pub fn distance_field_by(
+        &self,
+        sources: impl IntoIterator<Item = Coord>,
+        can_step: impl Fn(&T, &T) -> bool,
+    ) -> Grid<Option<u32>> {
+        let mut distances: Grid<Option<u32>> = Grid::new_sized_as(self);
+        let mut queue = VecDeque::new();
+        for source in sources {
+            if let Some(distance @ None) = distances.get_mut(source) {
+                *distance = Some(0);
+                queue.push_back((source, 0));
+            }
+        }
+
+        while let Some((coord, distance)) = queue.pop_front() {
+            for offset in DIRECTIONS_CARDINAL {
+                let next = coord + offset;
+                let Some(next_value) = self.get(next) else {
+                    continue;
+                };
+                if distances[next].is_none() && can_step(&self[coord], next_value) {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    // ordered pairs of different cells at most the manhattan distance apart, with their distance
+    pub fn pairs_within_manhattan(&self, distance: u64) -> impl Iterator<Item = (Coord, Coord, u64)> + '_ {
+        Offset::within_manhattan(distance).into_iter().flat_map(move |offset| {
+            self.iter()
+                .map(move |(from, _)| (from, from + offset, offset.manhattan()))
+                .filter(|&(_, to, _)| self.is_valid(to))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Coord, Grid, Offset};
+
+    #[test]
+    fn measures_from_nearest_source() {
+        let grid = Grid::<char>::parse_str("...#.\n.#.#.\n.#...").unwrap();
+        let distances = grid.distance_field([Coord { x: 0, y: 0 }, Coord { x: 4, y: 0 }], |&c| c == '.');
+        let rows: Vec<Vec<Option<u32>>> = distances.iter_rows().map(|row| row.to_vec()).collect();
+        assert_eq!(
+            rows,
+            [
+                [Some(0), Some(1), Some(2), None, Some(0)],
+                [Some(1), None, Some(3), None, Some(1)],
+                [Some(2), None, Some(4), Some(3), Some(2)],
+            ]
+        );
+    }
+
+    #[test]
+    fn steps_depend_on_both_cells() {
+        let grid = Grid::<char>::parse_str("0123\n1x34\n2345").unwrap();
+        let distances = grid.distance_field_by([Coord::default()], |&from, &to| to as u8 == from as u8 + 1);
+        assert_eq!(distances[Coord { x: 3, y: 2 }], Some(5));
+        assert_eq!(distances[Coord { x: 1, y: 1 }], None);
+    }
+
+    #[test]
+    fn pairs_within_manhattan_distance() {
+        assert_eq!(Offset::within_manhattan(2).len(), 12);
+        assert_eq!(Offset { x: -3, y: 2 }.manhattan(), 5);
+
+        let grid = Grid::<char>::parse_str("..\n..").unwrap();
+        let mut pairs: Vec<_> = grid.pairs_within_manhattan(1).collect();
+        pairs.sort();
+        assert_eq!(pairs.len(), 8);
+        assert_eq!(pairs[0], (Coord { x: 0, y: 0 }, Coord { x: 0, y: 1 }, 1));
+        assert_eq!(grid.pairs_within_manhattan(2).count(), 12);
+    }
+}
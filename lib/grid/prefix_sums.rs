@@ -0,0 +1,83 @@
+use crate::geometry::Rect;
+use crate::grid::{Coord, Grid};
+use crate::math::Integer;
+
+// summed-area table, for sums over rectangles of cells in constant time
+#[derive(Clone, Debug)]
+pub struct PrefixSums<S> {
+    // sums of every cell above and left of the coordinate, with an extra zero row and column first
+    sums: Grid<S>,
+}
+
+impl<T> Grid<T> {
+    // sums of the values of the cells, for a grid of compressed cells weighted by their real areas too
+    pub fn prefix_sums<S: Integer>(&self, value: impl Fn(Coord, &T) -> S) -> PrefixSums<S> {
+        let mut sums = Grid::new_with_values(self.columns() + 1, self.rows() + 1, S::ZERO);
+        for (coord, item) in self.iter() {
+            let (x, y) = (coord.x as usize, coord.y as usize);
+            let sum = value(coord, item) + *sums.index(x, y + 1) + *sums.index(x + 1, y) - *sums.index(x, y);
+            *sums.index_mut(x + 1, y + 1) = sum;
+        }
+        PrefixSums { sums }
+    }
+}
+
+impl<S: Integer> PrefixSums<S> {
+    // sum of the cells of the grid within the rectangle
+    pub fn sum(&self, rect: Rect) -> S {
+        let Some(cells) = Rect::from_size(self.sums.columns() - 1, self.sums.rows() - 1).and_then(|grid| grid.intersection(&rect)) else {
+            return S::ZERO;
+        };
+        let (min_x, min_y) = (cells.min.x as usize, cells.min.y as usize);
+        let (max_x, max_y) = (cells.max.x as usize + 1, cells.max.y as usize + 1);
+        // adding before subtracting, for unsigned sums
+        *self.sums.index(max_x, max_y) + *self.sums.index(min_x, min_y) - *self.sums.index(min_x, max_y)
+            - *self.sums.index(max_x, min_y)
+    }
+
+    // sum of all the cells
+    pub fn total(&self) -> S {
+        *self.sums.index(self.sums.columns() - 1, self.sums.rows() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Rect;
+    use crate::grid::{coord, Compression, Grid};
+
+    #[test]
+    fn sums_rectangles() {
+        let grid = Grid::<char>::parse_str("#..#\n.##.\n####").unwrap();
+        let sums = grid.prefix_sums(|_, &c| u32::from(c == '#'));
+        assert_eq!(sums.total(), 8);
+        assert_eq!(sums.sum(Rect::new(coord(1, 1), coord(2, 2))), 4);
+        assert_eq!(sums.sum(Rect::new(coord(1, 0), coord(2, 0))), 0);
+        assert_eq!(sums.sum(Rect::new(coord(3, 0), coord(3, 2))), 2);
+        // clipped to the grid
+        assert_eq!(sums.sum(Rect::new(coord(-5, 2), coord(1, 9))), 2);
+        assert_eq!(sums.sum(Rect::new(coord(4, 0), coord(6, 2))), 0);
+
+        // every rectangle against counting its cells
+        for (a, _) in grid.iter() {
+            for (b, _) in grid.iter() {
+                let query = Rect::new(a, b);
+                let counted = grid.iter().filter(|&(cell, &c)| query.contains(cell) && c == '#').count();
+                assert_eq!(sums.sum(query) as usize, counted, "{query:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn sums_real_areas_of_compressed_cells() {
+        let compression = Compression::new([coord(0, 0), coord(10, 0), coord(10, 20), coord(0, 20)]);
+        // cells 1..=9 by 1..=19 and the real points of the top left corner
+        let mut marked = compression.grid(false);
+        marked[coord(1, 1)] = true;
+        marked[coord(0, 0)] = true;
+        let sums = marked.prefix_sums(|cell, &marked| if marked { compression.area(cell) } else { 0 });
+        assert_eq!(sums.total(), 9 * 19 + 1);
+        let cells = compression.compress_rect(Rect::new(coord(5, 5), coord(10, 20))).unwrap();
+        assert_eq!(sums.sum(cells), 9 * 19);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::geometry::Rect;
+use crate::grid::{Coord, Grid, Offset, DIRECTIONS_8, DIRECTIONS_CARDINAL};
+use std::collections::HashSet;
+use std::ops::Index;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    // only through shared edges
+    Four,
+    // also through shared corners
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(self) -> &'static [Offset] {
+        match self {
+            Connectivity::Four => &DIRECTIONS_CARDINAL,
+            Connectivity::Eight => &DIRECTIONS_8,
+        }
+    }
+}
+
+// connected cells of a grid
+#[derive(Clone, Debug)]
+pub struct Region {
+    cells: HashSet<Coord>,
+    connectivity: Connectivity,
+}
+
+impl Region {
+    // cells in no particular order
+    pub fn cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells.iter().copied()
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.cells.contains(&coord)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    // number of cell edges between the region and anything else
+    pub fn perimeter(&self) -> usize {
+        self.boundary_edges().count()
+    }
+
+    // number of straight lines the perimeter is made of
+    pub fn sides(&self) -> usize {
+        // every side is counted by the edge at its end, the one without a continuation along the side
+        self.boundary_edges()
+            .filter(|&(coord, outwards)| {
+                let along = coord + outwards.rotate_right();
+                !self.contains(along) || self.contains(along + outwards)
+            })
+            .count()
+    }
+
+    // box around the region
+    pub fn bounds(&self) -> Rect {
+        Rect::from_points(self.cells()).expect("region has at least one cell")
+    }
+
+    // cells with a neighbour outside of the region, by the region's connectivity
+    pub fn boundary(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells()
+            .filter(|&coord| self.connectivity.offsets().iter().any(|&offset| !self.contains(coord + offset)))
+    }
+
+    // cells with the direction out of the region, once per edge
+    fn boundary_edges(&self) -> impl Iterator<Item = (Coord, Offset)> + '_ {
+        self.cells().flat_map(move |coord| {
+            DIRECTIONS_CARDINAL
+                .iter()
+                .filter(move |&&offset| !self.contains(coord + offset))
+                .map(move |&offset| (coord, offset))
+        })
+    }
+}
+
+// regions of a grid, labelled by their index
+#[derive(Clone, Debug)]
+pub struct Regions {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+
+    // label of the region of every cell, none for cells in no region
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn label(&self, coord: Coord) -> Option<usize> {
+        self.labels.get(coord).copied().flatten()
+    }
+
+    pub fn region_at(&self, coord: Coord) -> Option<&Region> {
+        self.label(coord).map(|label| &self.regions[label])
+    }
+}
+
+impl Index<usize> for Regions {
+    type Output = Region;
+
+    fn index(&self, label: usize) -> &Self::Output {
+        &self.regions[label]
+    }
+}
+
+impl<'a> IntoIterator for &'a Regions {
+    type Item = &'a Region;
+    type IntoIter = std::slice::Iter<'a, Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Grid<T> {
+    // every cell in the region of its neighbours it is the same as, labelled in the order of their first cell
+    pub fn regions(&self, connectivity: Connectivity, same: impl Fn(&T, &T) -> bool) -> Regions {
+        self.label_regions(connectivity, |_| true, same)
+    }
+
+    // only the cells matching the predicate, in regions of any matching neighbours
+    pub fn regions_where(&self, connectivity: Connectivity, predicate: impl Fn(&T) -> bool) -> Regions {
+        self.label_regions(connectivity, predicate, |_, _| true)
+    }
+
+    fn label_regions(
+        &self,
+        connectivity: Connectivity,
+        include: impl Fn(&T) -> bool,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        let mut labels: Grid<Option<usize>> = Grid::new_sized_as(self);
+        let mut regions = vec![];
+
+        for (start, value) in self.iter() {
+            if labels[start].is_some() || !include(value) {
+                continue;
+            }
+            let label = regions.len();
+            let mut cells = HashSet::from([start]);
+            labels[start] = Some(label);
+            let mut stack = vec![start];
+            while let Some(coord) = stack.pop() {
+                for &offset in connectivity.offsets() {
+                    let next = coord + offset;
+                    let Some(next_value) = self.get(next) else {
+                        continue;
+                    };
+                    if labels[next].is_none() && include(next_value) && same(&self[coord], next_value) {
+                        labels[next] = Some(label);
+                        cells.insert(next);
+                        stack.push(next);
+                    }
+                }
+            }
+            regions.push(Region { cells, connectivity });
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Rect;
+    use crate::grid::{Connectivity, Coord, Grid};
+    use rstest::rstest;
+
+    #[test]
+    fn labels_regions_of_same_cells() {
+        let grid = Grid::<char>::parse_str("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let regions = grid.regions(Connectivity::Four, |a, b| a == b);
+        assert_eq!(regions.len(), 5);
+        assert_eq!(regions.label(Coord { x: 3, y: 0 }), Some(0));
+        assert_eq!(regions.label(Coord { x: 2, y: 1 }), Some(2));
+        assert_eq!(regions.label(Coord { x: 4, y: 0 }), None);
+
+        let areas: Vec<_> = regions.iter().map(|region| (region.area(), region.perimeter(), region.sides())).collect();
+        assert_eq!(areas, [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]);
+
+        let c = regions.region_at(Coord { x: 3, y: 3 }).unwrap();
+        assert_eq!(c.bounds(), Rect::new(Coord { x: 2, y: 1 }, Coord { x: 3, y: 3 }));
+    }
+
+    #[rstest]
+    #[case(Connectivity::Four, 3)]
+    #[case(Connectivity::Eight, 1)]
+    fn connects_diagonals_by_connectivity(#[case] connectivity: Connectivity, #[case] expected: usize) {
+        let grid = Grid::<char>::parse_str("#..\n.#.\n..#").unwrap();
+        assert_eq!(grid.regions_where(connectivity, |&c| c == '#').len(), expected);
+    }
+
+    #[test]
+    fn counts_sides_around_holes() {
+        let grid = Grid::<char>::parse_str("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO").unwrap();
+        let regions = grid.regions_where(Connectivity::Four, |&c| c == 'O');
+        assert_eq!(regions.len(), 1);
+        assert_eq!((regions[0].area(), regions[0].perimeter(), regions[0].sides()), (21, 36, 20));
+        assert_eq!(regions[0].boundary().count(), 20);
+        assert_eq!(regions.label(Coord { x: 1, y: 1 }), None);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::geometry::Rect;
+use crate::grid::{Coord, Grid, Offset};
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+// grid of only the set cells, unbounded in every direction
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    points: HashMap<Coord, T>,
+    // inclusive corners of every coordinate ever inserted
+    bounds: Option<Rect>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            points: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    // box around the inserted cells, it does not shrink on remove
+    pub fn bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    pub fn insert(&mut self, coord: Coord, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => Rect::new(coord, coord),
+            Some(bounds) => bounds.include(coord),
+        });
+        self.points.insert(coord, value)
+    }
+
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        self.points.remove(&coord)
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.points.contains_key(&coord)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.points.get(&coord)
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.points.get_mut(&coord)
+    }
+
+    // set cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.points.iter().map(|(&coord, value)| (coord, value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        self.points.iter_mut().map(|(&coord, value)| (coord, value))
+    }
+
+    // set cells next to the coordinate, in the given directions
+    pub fn neighbours<'a>(
+        &'a self,
+        coord: Coord,
+        directions: &'a [Offset],
+    ) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        directions.iter().filter_map(move |&offset| {
+            let next = coord + offset;
+            self.get(next).map(|value| (next, value))
+        })
+    }
+
+    // dense copy of the bounds, where the smallest corner becomes 0,0 and unset cells are empty
+    pub fn to_grid(&self, empty: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds else {
+            return Grid::new_with_values(0, 0, empty);
+        };
+        let (columns, rows) = bounds.size();
+        let min = bounds.min;
+        let mut grid = Grid::new_with_values(columns, rows, empty);
+        for (&coord, value) in &self.points {
+            grid[Coord {
+                x: coord.x - min.x,
+                y: coord.y - min.y,
+            }] = value.clone();
+        }
+        grid
+    }
+}
+
+impl<T> Index<Coord> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        &self.points[&coord]
+    }
+}
+
+impl<T> IndexMut<Coord> for SparseGrid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.points.get_mut(&coord).expect("no value at coordinate")
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Coord, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Coord, T)>>(&mut self, iter: I) {
+        for (coord, value) in iter {
+            self.insert(coord, value);
+        }
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = (Coord, T);
+    type IntoIter = hash_map::IntoIter<Coord, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Rect;
+    use crate::grid::{Coord, SparseGrid, DIRECTIONS_CARDINAL};
+
+    #[test]
+    fn bounds_grow_with_inserts() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Coord { x: 500, y: 0 }, 'o');
+        grid.insert(Coord { x: 498, y: 9 }, '#');
+        grid.insert(Coord { x: 503, y: -2 }, '#');
+        assert_eq!(grid.bounds(), Some(Rect::new(Coord { x: 498, y: -2 }, Coord { x: 503, y: 9 })));
+
+        assert_eq!(grid.remove(Coord { x: 498, y: 9 }), Some('#'));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some(Rect::new(Coord { x: 498, y: -2 }, Coord { x: 503, y: 9 })));
+    }
+
+    #[test]
+    fn neighbours_are_only_set_cells() {
+        let grid: SparseGrid<u8> = [(Coord { x: 1, y: 0 }, 1), (Coord { x: 0, y: -1 }, 2), (Coord { x: 5, y: 5 }, 3)]
+            .into_iter()
+            .collect();
+        let mut neighbours: Vec<_> = grid.neighbours(Coord::default(), &DIRECTIONS_CARDINAL).collect();
+        neighbours.sort();
+        assert_eq!(neighbours, [(Coord { x: 0, y: -1 }, &2), (Coord { x: 1, y: 0 }, &1)]);
+    }
+
+    #[test]
+    fn converts_to_dense_grid() {
+        let grid: SparseGrid<char> = [(Coord { x: -1, y: 3 }, '#'), (Coord { x: 1, y: 4 }, 'o')].into_iter().collect();
+        let dense = grid.to_grid('.');
+        assert_eq!((dense.columns(), dense.rows()), (3, 2));
+        let rows: Vec<String> = (0..2)
+            .map(|y| (0..3).map(|x| *dense.index(x, y)).collect())
+            .collect();
+        assert_eq!(rows, ["#..", "..o"]);
+    }
+}
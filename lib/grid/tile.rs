@@ -0,0 +1,207 @@
+use crate::grid::{Coord, FromLinesTryIterMapError, Grid};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+// cell of a grid drawn as a single character, for parsing and printing
+pub trait GridTile: Sized {
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+// implements GridTile for an enum of unit variants, e.g. grid_tile!(Tile { '#' => Tile::Wall, '.' => Tile::Empty })
+#[macro_export]
+macro_rules! grid_tile {
+    ($tile:ty { $($c:literal => $variant:path),* $(,)? }) => {
+        impl $crate::grid::GridTile for $tile {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    $($c => Some($variant),)*
+                    _ => None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $($variant => $c,)*
+                }
+            }
+        }
+    };
+}
+
+impl GridTile for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+// '#' for set, '.' for unset cells
+impl GridTile for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    #[error("invalid tile '{tile}' at {},{}", .coord.x, .coord.y)]
+    InvalidTile { tile: char, coord: Coord },
+    #[error("inconsistent line {0} length")]
+    InconsistentLineLengths(usize),
+    #[error("marker '{marker}' found at both {},{} and {},{}", .first.x, .first.y, .second.x, .second.y)]
+    DuplicateMarker { marker: char, first: Coord, second: Coord },
+    #[error("marker '{0}' not found")]
+    MissingMarker(char),
+}
+
+impl<T: GridTile> Grid<T> {
+    // grid of lines of tiles, up to the first empty line
+    pub fn parse_str(s: &str) -> Result<Grid<T>, ParseGridError> {
+        Grid::from_lines_try_iter_map(
+            s.lines()
+                .take_while(|line| !line.is_empty())
+                .map(|line| -> Result<_, Infallible> { Ok(line.chars()) }),
+            |coord, tile| T::from_char(tile).ok_or(ParseGridError::InvalidTile { tile, coord }),
+        )
+        .map_err(from_lines_error)
+    }
+
+    // like parse_str, with every marker, e.g. ('S', '.') for a start on an empty tile, found exactly once
+    pub fn parse_str_with_markers(
+        s: &str,
+        markers: &[(char, char)],
+    ) -> Result<(Grid<T>, HashMap<char, Coord>), ParseGridError> {
+        let mut found: HashMap<char, Coord> = HashMap::new();
+        let grid = Grid::from_lines_try_iter_map(
+            s.lines()
+                .take_while(|line| !line.is_empty())
+                .map(|line| -> Result<_, Infallible> { Ok(line.chars()) }),
+            |coord, c| {
+                let tile = match markers.iter().find(|&&(marker, _)| marker == c) {
+                    Some(&(marker, tile)) => {
+                        if let Some(&first) = found.get(&marker) {
+                            return Err(ParseGridError::DuplicateMarker {
+                                marker,
+                                first,
+                                second: coord,
+                            });
+                        }
+                        found.insert(marker, coord);
+                        tile
+                    }
+                    None => c,
+                };
+                T::from_char(tile).ok_or(ParseGridError::InvalidTile { tile: c, coord })
+            },
+        )
+        .map_err(from_lines_error)?;
+
+        if let Some(&(missing, _)) = markers.iter().find(|(marker, _)| !found.contains_key(marker)) {
+            return Err(ParseGridError::MissingMarker(missing));
+        }
+        Ok((grid, found))
+    }
+}
+
+fn from_lines_error(err: FromLinesTryIterMapError<Infallible, ParseGridError>) -> ParseGridError {
+    match err {
+        FromLinesTryIterMapError::LinesIteration(never, _) => match never {},
+        FromLinesTryIterMapError::LineIteration(err, _, _) => err,
+        FromLinesTryIterMapError::InconsistentLineLengths(line) => ParseGridError::InconsistentLineLengths(line),
+    }
+}
+
+// every row on its own line, as parse_str reads them
+impl<T: GridTile> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.iter_rows() {
+            let line: String = row.iter().map(GridTile::to_char).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Coord, Grid, GridTile, ParseGridError};
+
+    #[derive(Debug, PartialEq)]
+    enum Tile {
+        Wall,
+        Box,
+        Empty,
+    }
+
+    grid_tile!(Tile {
+        '#' => Tile::Wall,
+        'O' => Tile::Box,
+        '.' => Tile::Empty,
+    });
+
+    #[test]
+    fn parses_and_prints_tiles() {
+        let map = "#####\n#.O.#\n#####\n\n<^>\n";
+        let grid = Grid::<Tile>::parse_str(map).unwrap();
+        assert_eq!((grid.columns(), grid.rows()), (5, 3));
+        assert_eq!(grid[Coord { x: 2, y: 1 }], Tile::Box);
+        assert_eq!(grid.to_string(), "#####\n#.O.#\n#####\n");
+        assert_eq!(Tile::from_char('#'), Some(Tile::Wall));
+    }
+
+    #[test]
+    fn reports_invalid_tiles() {
+        assert_eq!(
+            Grid::<Tile>::parse_str("##\n#@\n").unwrap_err(),
+            ParseGridError::InvalidTile {
+                tile: '@',
+                coord: Coord { x: 1, y: 1 }
+            }
+        );
+        assert_eq!(Grid::<bool>::parse_str("##\n#\n").unwrap_err(), ParseGridError::InconsistentLineLengths(1));
+        assert_eq!(Grid::<bool>::parse_str(".#\n").unwrap().to_string(), ".#\n");
+    }
+
+    #[test]
+    fn extracts_markers() {
+        let (grid, markers) = Grid::<Tile>::parse_str_with_markers("#####\n#S.E#\n#####\n", &[('S', '.'), ('E', '.')]).unwrap();
+        assert_eq!(markers[&'S'], Coord { x: 1, y: 1 });
+        assert_eq!(markers[&'E'], Coord { x: 3, y: 1 });
+        assert_eq!(grid.to_string(), "#####\n#...#\n#####\n");
+    }
+
+    #[test]
+    fn markers_must_be_unique() {
+        assert_eq!(
+            Grid::<Tile>::parse_str_with_markers("#S#\n#S#\n", &[('S', '.')]).unwrap_err(),
+            ParseGridError::DuplicateMarker {
+                marker: 'S',
+                first: Coord { x: 1, y: 0 },
+                second: Coord { x: 1, y: 1 }
+            }
+        );
+        assert_eq!(
+            Grid::<Tile>::parse_str_with_markers("#S#\n", &[('S', '.'), ('E', '.')]).unwrap_err(),
+            ParseGridError::MissingMarker('E')
+        );
+    }
+}
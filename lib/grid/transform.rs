@@ -0,0 +1,112 @@
+use crate::grid::{Coord, Grid};
+
+impl<T> Grid<T> {
+    // grid filled by the value of every coordinate
+    pub fn from_fn(columns: usize, rows: usize, mut func: impl FnMut(Coord) -> T) -> Grid<T> {
+        let mut points = Vec::with_capacity(columns * rows);
+        for y in 0..rows {
+            for x in 0..columns {
+                points.push(func(Coord { x: x as i64, y: y as i64 }));
+            }
+        }
+        Grid { points, rows, columns }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.points[y * self.columns..(y + 1) * self.columns]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.points[y * self.columns..(y + 1) * self.columns]
+    }
+
+    // column from the top down
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.columns, "column {x} out of {} columns", self.columns);
+        self.points[x..].iter().step_by(self.columns)
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.points.chunks_exact(self.columns.max(1))
+    }
+
+    pub fn iter_columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + ExactSizeIterator {
+        (0..self.columns).map(|x| self.column(x))
+    }
+}
+
+// new grids with the cells rearranged, so that an algorithm written for one direction serves them all
+impl<T: Clone> Grid<T> {
+    // rows become columns, mirroring along the main diagonal
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.rows, self.columns, |c| self.index(c.y as usize, c.x as usize).clone())
+    }
+
+    // the left column becomes the top row
+    pub fn rotate_cw(&self) -> Grid<T> {
+        Grid::from_fn(self.rows, self.columns, |c| {
+            self.index(c.y as usize, self.rows - 1 - c.x as usize).clone()
+        })
+    }
+
+    // the right column becomes the top row
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        Grid::from_fn(self.rows, self.columns, |c| {
+            self.index(self.columns - 1 - c.y as usize, c.x as usize).clone()
+        })
+    }
+
+    // mirrors left and right
+    pub fn flip_h(&self) -> Grid<T> {
+        Grid::from_fn(self.columns, self.rows, |c| {
+            self.index(self.columns - 1 - c.x as usize, c.y as usize).clone()
+        })
+    }
+
+    // mirrors top and bottom
+    pub fn flip_v(&self) -> Grid<T> {
+        Grid::from_fn(self.columns, self.rows, |c| {
+            self.index(c.x as usize, self.rows - 1 - c.y as usize).clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use rstest::rstest;
+
+    fn lines(grid: &Grid<char>) -> Vec<String> {
+        grid.iter_rows().map(|row| row.iter().collect()).collect()
+    }
+
+    #[rstest]
+    #[case(Grid::transpose, &["ad", "be", "cf"])]
+    #[case(Grid::rotate_cw, &["da", "eb", "fc"])]
+    #[case(Grid::rotate_ccw, &["cf", "be", "ad"])]
+    #[case(Grid::flip_h, &["cba", "fed"])]
+    #[case(Grid::flip_v, &["def", "abc"])]
+    fn transforms(#[case] transform: fn(&Grid<char>) -> Grid<char>, #[case] expected: &[&str]) {
+        assert_eq!(lines(&transform(&Grid::<char>::parse_str("abc\ndef").unwrap())), expected);
+    }
+
+    #[test]
+    fn four_rotations_are_identity() {
+        let original = Grid::<char>::parse_str("abc\ndef").unwrap();
+        let rotated = original.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(rotated, original);
+        assert_eq!(original.rotate_cw().rotate_ccw(), original);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let mut grid = Grid::<char>::parse_str("abc\ndef").unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(0).rev().collect::<String>(), "da");
+        assert_eq!(grid.iter_columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+
+        grid.row_mut(0)[1] = 'x';
+        assert_eq!(lines(&grid), ["axc", "def"]);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::grid::{Coord, Grid, Offset};
+use std::ops::Index;
+
+// view of a grid repeated infinitely in every direction, the grid itself being the tile 0,0.
+// Only non-empty grids can be wrapped, so every coordinate has a cell.
+pub struct Wrapping<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+// not derived, as that would require T: Copy
+impl<T> Clone for Wrapping<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Wrapping<'_, T> {}
+
+impl<T> Grid<T> {
+    // none for a grid without rows or columns, having nothing to repeat
+    pub fn wrapping(&self) -> Option<Wrapping<'_, T>> {
+        (self.columns > 0 && self.rows > 0).then_some(Wrapping { grid: self })
+    }
+}
+
+impl Coord {
+    // coordinate wrapped into a columns x rows area, none if the area is empty or too large
+    pub fn wrapped(self, columns: usize, rows: usize) -> Option<Coord> {
+        Some(Coord {
+            x: self.x.checked_rem_euclid(i64::try_from(columns).ok()?)?,
+            y: self.y.checked_rem_euclid(i64::try_from(rows).ok()?)?,
+        })
+    }
+}
+
+impl<'a, T> Wrapping<'a, T> {
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    // the same cell within the grid
+    pub fn wrap(&self, coord: Coord) -> Coord {
+        coord.wrapped(self.grid.columns, self.grid.rows).expect("non-empty grid")
+    }
+
+    // copy of the grid the coordinate falls in, e.g. -1,0 for the one left of the grid
+    pub fn tile(&self, coord: Coord) -> Offset {
+        Offset {
+            x: coord.x.div_euclid(self.grid.columns as i64),
+            y: coord.y.div_euclid(self.grid.rows as i64),
+        }
+    }
+
+    pub fn split(&self, coord: Coord) -> (Coord, Offset) {
+        (self.wrap(coord), self.tile(coord))
+    }
+
+    pub fn get(&self, coord: Coord) -> &'a T {
+        self.grid.index_coord(self.wrap(coord))
+    }
+
+    // cells next to the coordinate in the given directions, the coordinates are not wrapped
+    pub fn neighbours(&self, coord: Coord, directions: &'a [Offset]) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        let wrapping = *self;
+        directions
+            .iter()
+            .map(move |&offset| (coord + offset, wrapping.get(coord + offset)))
+    }
+
+    // endless line from the coordinate, the coordinates are not wrapped
+    pub fn iter_line(&self, from: Coord, direction: Offset) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        let wrapping = *self;
+        std::iter::successors(Some(from), move |&coord| Some(coord + direction))
+            .map(move |coord| (coord, wrapping.get(coord)))
+    }
+}
+
+impl<T> Index<Coord> for Wrapping<'_, T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Coord, Grid, Offset, DIRECTIONS_CARDINAL, OFFSET_LEFT};
+
+    fn grid() -> Grid<char> {
+        Grid::parse_str("ab\ncd\nef").unwrap()
+    }
+
+    #[test]
+    fn wraps_coordinates_into_tiles() {
+        let grid = grid();
+        let wrapping = grid.wrapping().unwrap();
+        assert_eq!(wrapping.split(Coord { x: 1, y: 2 }), (Coord { x: 1, y: 2 }, Offset { x: 0, y: 0 }));
+        assert_eq!(wrapping.split(Coord { x: -1, y: 3 }), (Coord { x: 1, y: 0 }, Offset { x: -1, y: 1 }));
+        assert_eq!(wrapping.split(Coord { x: -3, y: -4 }), (Coord { x: 1, y: 2 }, Offset { x: -2, y: -2 }));
+        assert_eq!(wrapping[Coord { x: 4, y: -1 }], 'e');
+    }
+
+    #[test]
+    fn neighbours_wrap_around_edges() {
+        let grid = grid();
+        let neighbours: Vec<_> = grid.wrapping().unwrap().neighbours(Coord { x: 0, y: 0 }, &DIRECTIONS_CARDINAL).collect();
+        assert_eq!(
+            neighbours,
+            [
+                (Coord { x: 1, y: 0 }, &'b'),
+                (Coord { x: 0, y: 1 }, &'c'),
+                (Coord { x: -1, y: 0 }, &'b'),
+                (Coord { x: 0, y: -1 }, &'e'),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_continues_past_edges() {
+        let grid = grid();
+        let line: String = grid.wrapping().unwrap().iter_line(Coord { x: 0, y: 1 }, OFFSET_LEFT).take(5).map(|(_, &c)| c).collect();
+        assert_eq!(line, "cdcdc");
+    }
+
+    #[test]
+    fn refuses_empty_grids() {
+        assert!(Grid::<char>::new(0, 0).wrapping().is_none());
+        assert!(Grid::<char>::new(3, 0).wrapping().is_none());
+        assert!(Grid::<char>::new(0, 3).wrapping().is_none());
+    }
+
+    #[test]
+    fn wraps_without_grid() {
+        assert_eq!(Coord { x: -1, y: 9 }.wrapped(11, 7), Some(Coord { x: 10, y: 2 }));
+        assert_eq!(Coord { x: -1, y: 9 }.wrapped(0, 7), None);
+        assert_eq!(Coord { x: -1, y: 9 }.wrapped(11, 0), None);
+        assert_eq!(Coord { x: -1, y: 9 }.wrapped(usize::MAX, 7), None);
+    }
+}
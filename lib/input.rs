@@ -0,0 +1,149 @@
+use std::io::{read_to_string, stdin};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+use thiserror::Error;
+
+// file name of inputs kept next to the solution source
+pub const IN_TREE_INPUT: &str = "input";
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("no input for {year}-{day}, expected it at {}", .path.display())]
+    Missing { year: u16, day: u8, path: PathBuf },
+    #[error("reading input {}: {source}", .path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("reading input from stdin: {0}")]
+    Stdin(io::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Cached(PathBuf),
+    InTree(PathBuf),
+    Stdin,
+}
+
+// finds puzzle inputs, first in the cache directory (inputs/YYYY/DD.txt), then next to the solution
+pub struct Inputs {
+    cache_dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Inputs {
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    // input file of the day, if there is one
+    pub fn find(&self, year: u16, day: u8, solution_dir: &Path) -> Option<InputSource> {
+        let cached = self.cache_path(year, day);
+        if cached.is_file() {
+            return Some(InputSource::Cached(cached));
+        }
+        let in_tree = solution_dir.join(IN_TREE_INPUT);
+        if in_tree.is_file() {
+            return Some(InputSource::InTree(in_tree));
+        }
+        None
+    }
+
+    // like find, but an error naming the expected path when there is no input. Stdin is no fallback,
+    // as any non-terminal stdin (CI, cargo test, redirects) would be read silently, it is only read
+    // when asked for explicitly
+    pub fn resolve(&self, year: u16, day: u8, solution_dir: &Path) -> Result<InputSource, InputError> {
+        self.find(year, day, solution_dir).ok_or_else(|| InputError::Missing {
+            year,
+            day,
+            path: self.cache_path(year, day),
+        })
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Cached(path) | InputSource::InTree(path) => {
+                fs::read_to_string(path).map_err(|source| InputError::Read {
+                    path: path.clone(),
+                    source,
+                })
+            }
+            InputSource::Stdin => read_to_string(stdin()).map_err(InputError::Stdin),
+        }
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Cached(path) | InputSource::InTree(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{InputError, InputSource, Inputs};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn cache_path_pads_day() {
+        let inputs = Inputs::new("inputs");
+        assert_eq!(inputs.cache_path(2024, 5), PathBuf::from("inputs/2024/05.txt"));
+    }
+
+    #[test]
+    fn cache_takes_precedence_over_in_tree_input() {
+        let dir = temp_dir("precedence");
+        let solution_dir = dir.join("2023-5");
+        fs::create_dir_all(&solution_dir).unwrap();
+        fs::write(solution_dir.join("input"), "in tree").unwrap();
+
+        let inputs = Inputs::new(dir.join("inputs"));
+        assert_eq!(
+            inputs.find(2023, 5, &solution_dir),
+            Some(InputSource::InTree(solution_dir.join("input")))
+        );
+
+        fs::create_dir_all(dir.join("inputs/2023")).unwrap();
+        fs::write(dir.join("inputs/2023/05.txt"), "cached").unwrap();
+        let source = inputs.find(2023, 5, &solution_dir).unwrap();
+        assert_eq!(source, InputSource::Cached(dir.join("inputs/2023/05.txt")));
+        assert_eq!(source.read().unwrap(), "cached");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_input_is_not_found() {
+        let dir = temp_dir("missing");
+        let inputs = Inputs::new(dir.join("inputs"));
+        assert_eq!(inputs.find(2024, 1, &dir), None);
+
+        let error = inputs.resolve(2024, 1, &dir).unwrap_err();
+        assert!(matches!(error, InputError::Missing { year: 2024, day: 1, .. }));
+        assert!(error.to_string().ends_with("inputs/2024/01.txt"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
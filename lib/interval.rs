@@ -0,0 +1,367 @@
+use crate::math::Integer;
+use std::ops::{Range, RangeInclusive};
+
+// set of values, kept as sorted, disjoint and non-adjacent half-open ranges
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // number of values in the set
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::ZERO, |len, range| len + (range.end - range.start))
+    }
+
+    // ranges from the smallest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Range<T>> + ExactSizeIterator + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // ranges overlapping or touching the new one, to be merged with it
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first < last {
+            range.start.min(self.ranges[first].start)..range.end.max(self.ranges[last - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    // none if the range ends at the largest value, which a half-open range can not hold
+    #[must_use]
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) -> Option<()> {
+        self.insert(*range.start()..range.end().checked_add(T::ONE)?);
+        Some(())
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+        let left = self.ranges[first].start..range.start;
+        let right = range.end..self.ranges[last - 1].end;
+        self.ranges.splice(first..last, [left, right].into_iter().filter(|r| !r.is_empty()));
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    // whether every value of the range is in the set
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges.get(i).is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    // whether any value of the range is in the set
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges.get(i).is_some_and(|r| r.start < range.end)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        result.extend(other.iter());
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = vec![];
+        let (mut a, mut b) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let common = x.start.max(y.start)..x.end.min(y.end);
+            if !common.is_empty() {
+                ranges.push(common);
+            }
+            // the range ending first can not overlap anything further
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+// values of disjoint half-open ranges, sorted by them
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalMap<T, V> {
+    entries: Vec<(Range<T>, V)>,
+}
+
+impl<T, V> Default for IntervalMap<T, V> {
+    fn default() -> Self {
+        IntervalMap { entries: vec![] }
+    }
+}
+
+impl<T: Integer, V: Clone> IntervalMap<T, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // ranges with their values, from the smallest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Range<T>, &V)> + ExactSizeIterator {
+        self.entries.iter().map(|(range, value)| (range.clone(), value))
+    }
+
+    // the range takes the value, replacing whatever it overlaps
+    pub fn insert(&mut self, range: Range<T>, value: V) {
+        if range.is_empty() {
+            return;
+        }
+        self.remove(range.clone());
+        let i = self.entries.partition_point(|(r, _)| r.end <= range.start);
+        self.entries.insert(i, (range, value));
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.entries.partition_point(|(r, _)| r.end <= range.start);
+        let last = self.entries.partition_point(|(r, _)| r.start < range.end);
+        if first == last {
+            return;
+        }
+        let left = (self.entries[first].0.start..range.start, self.entries[first].1.clone());
+        let right = (range.end..self.entries[last - 1].0.end, self.entries[last - 1].1.clone());
+        self.entries.splice(first..last, [left, right].into_iter().filter(|(r, _)| !r.is_empty()));
+    }
+
+    pub fn get(&self, key: T) -> Option<&V> {
+        let i = self.entries.partition_point(|(r, _)| r.end <= key);
+        self.entries.get(i).filter(|(r, _)| r.start <= key).map(|(_, value)| value)
+    }
+
+    // the range cut where the values change, with the value of every piece, none for gaps
+    pub fn split(&self, range: Range<T>) -> Vec<(Range<T>, Option<&V>)> {
+        let mut pieces = vec![];
+        let mut start = range.start;
+        let first = self.entries.partition_point(|(r, _)| r.end <= range.start);
+        for (r, value) in &self.entries[first..] {
+            if start >= range.end {
+                break;
+            }
+            if start < r.start {
+                pieces.push((start..r.start.min(range.end), None));
+                start = r.start.min(range.end);
+            }
+            if start < range.end {
+                pieces.push((start..r.end.min(range.end), Some(value)));
+                start = r.end.min(range.end);
+            }
+        }
+        if start < range.end {
+            pieces.push((start..range.end, None));
+        }
+        pieces
+    }
+}
+
+// piecewise function moving every value by the offset of its range, and leaving others in place
+impl<T: Integer> IntervalMap<T, T> {
+    pub fn map(&self, value: T) -> T {
+        self.get(value).map_or(value, |&offset| value + offset)
+    }
+
+    // images of the pieces of the range, in the order of the pieces
+    pub fn map_range(&self, range: Range<T>) -> Vec<Range<T>> {
+        self.split(range)
+            .into_iter()
+            .map(|(piece, offset)| match offset {
+                Some(&offset) => piece.start + offset..piece.end + offset,
+                None => piece,
+            })
+            .collect()
+    }
+
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.iter().flat_map(|range| self.map_range(range)).collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use crate::interval::{IntervalMap, IntervalSet};
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::ops::Range;
+
+    // small deterministic pseudo random ranges within 0..64
+    fn random_ranges(seed: u64, count: usize) -> Vec<Range<i64>> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as i64 % 64
+        };
+        (0..count)
+            .map(|_| {
+                let start = next();
+                start..(start + next() % 12).min(64)
+            })
+            .collect()
+    }
+
+    fn values(ranges: &[Range<i64>]) -> BTreeSet<i64> {
+        ranges.iter().flat_map(|range| range.clone()).collect()
+    }
+
+    fn set_values(set: &IntervalSet<i64>) -> BTreeSet<i64> {
+        values(&set.iter().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let mut set: IntervalSet<i64> = [3..6, 10..15, 16..21, 12..19].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..6, 10..21]);
+        set.insert(6..10);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..21]);
+        set.remove(5..7);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..5, 7..21]);
+        assert_eq!(set.len(), 16);
+
+        let mut inclusive = IntervalSet::new();
+        assert_eq!(inclusive.insert_inclusive(3u8..=5), Some(()));
+        assert_eq!(inclusive.insert_inclusive(250u8..=254), Some(()));
+        assert_eq!(inclusive.insert_inclusive(200u8..=255), None);
+        assert_eq!(inclusive.len(), 8);
+        assert!(inclusive.contains(254) && !inclusive.contains(255));
+    }
+
+    #[test]
+    fn set_operations_match_brute_force() {
+        for seed in 0..50 {
+            let a_ranges = random_ranges(seed, 6);
+            let b_ranges = random_ranges(seed + 1000, 6);
+            let (a, b): (IntervalSet<i64>, IntervalSet<i64>) =
+                (a_ranges.iter().cloned().collect(), b_ranges.iter().cloned().collect());
+            let (a_values, b_values) = (values(&a_ranges), values(&b_ranges));
+
+            assert_eq!(set_values(&a), a_values);
+            assert_eq!(a.len(), a_values.len() as i64);
+            assert_eq!(set_values(&a.union(&b)), &a_values | &b_values);
+            assert_eq!(set_values(&a.intersection(&b)), &a_values & &b_values);
+            assert_eq!(set_values(&a.difference(&b)), &a_values - &b_values);
+            for value in -1..66 {
+                assert_eq!(a.contains(value), a_values.contains(&value));
+            }
+            for range in &b_ranges {
+                assert_eq!(a.contains_range(range), range.clone().all(|v| a_values.contains(&v)), "{range:?}");
+                assert_eq!(a.overlaps(range), range.clone().any(|v| a_values.contains(&v)), "{range:?}");
+            }
+            // merged ranges never touch
+            assert!(a.iter().zip(a.iter().skip(1)).all(|(x, y)| x.end < y.start));
+        }
+    }
+
+    #[test]
+    fn maps_ranges_through_offsets() {
+        let mut map = IntervalMap::new();
+        map.insert(50..60, 50i64);
+        assert_eq!(map.map_range(45..50), [45..50]);
+        assert_eq!(map.map_range(60..65), [60..65]);
+        assert_eq!(map.map_range(50..55), [100..105]);
+        assert_eq!(map.map_range(45..55), [45..50, 100..105]);
+        assert_eq!(map.map_range(55..65), [105..110, 60..65]);
+        assert_eq!(map.map(59), 109);
+        assert_eq!(map.map(60), 60);
+    }
+
+    #[test]
+    fn map_operations_match_brute_force() {
+        for seed in 0..50 {
+            let mut map = IntervalMap::new();
+            let mut brute: BTreeMap<i64, i64> = BTreeMap::new();
+            for (i, range) in random_ranges(seed, 8).into_iter().enumerate() {
+                let offset = i as i64 * 100 - 300;
+                if i % 3 == 2 {
+                    map.remove(range.clone());
+                    range.for_each(|v| {
+                        brute.remove(&v);
+                    });
+                } else {
+                    map.insert(range.clone(), offset);
+                    brute.extend(range.map(|v| (v, offset)));
+                }
+            }
+
+            for value in -1..66 {
+                assert_eq!(map.get(value), brute.get(&value));
+            }
+            for range in random_ranges(seed + 1000, 4) {
+                let pieces = map.split(range.clone());
+                let covered: Vec<i64> = pieces.iter().flat_map(|(piece, _)| piece.clone()).collect();
+                assert_eq!(covered, range.collect::<Vec<_>>());
+                for (piece, offset) in pieces {
+                    assert!(piece.clone().all(|v| brute.get(&v) == offset));
+                }
+            }
+
+            let source: IntervalSet<i64> = random_ranges(seed + 2000, 4).into_iter().collect();
+            let expected: BTreeSet<i64> = set_values(&source).into_iter().map(|v| v + brute.get(&v).unwrap_or(&0)).collect();
+            assert_eq!(set_values(&map.map_set(&source)), expected);
+        }
+    }
+}
@@ -1,2 +1,12 @@
+pub mod answers;
+pub mod cycle;
+pub mod fetch;
+pub mod geometry;
 pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod params;
+pub mod search;
+pub mod solution;
 pub mod str;
\ No newline at end of file
@@ -0,0 +1,269 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+// primitive integers the functions here work with, through their checked arithmetic
+pub trait Integer:
+    Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    // none for a zero divisor, or the smallest value of a signed type divided by -1
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    // none only for the smallest value of a signed type
+    fn checked_abs(self) -> Option<Self>;
+}
+
+// integers which can be negative, as the coefficients of extended_gcd can
+pub trait Signed: Integer {}
+
+macro_rules! impl_integer {
+    ($abs:ident => $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    $abs(self)
+                }
+            }
+        )*
+    };
+}
+
+fn signed_abs<T: Integer>(x: T) -> Option<T> {
+    if x < T::ZERO {
+        T::ZERO.checked_sub(x)
+    } else {
+        Some(x)
+    }
+}
+
+fn unsigned_abs<T>(x: T) -> Option<T> {
+    Some(x)
+}
+
+impl_integer!(signed_abs => i8, i16, i32, i64, i128, isize);
+impl_integer!(unsigned_abs => u8, u16, u32, u64, u128, usize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+// greatest common divisor, never negative, none on overflow
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a.checked_rem(b)?);
+    }
+    a.checked_abs()
+}
+
+// least common multiple, never negative, none on overflow
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+// least common multiple of all, 1 for none
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+// primes dividing n with their powers, from the smallest, none for n below 2
+pub fn prime_factors<T: Integer>(n: T) -> Vec<(T, u32)> {
+    let two = T::ONE.checked_add(T::ONE).expect("no integer type is this small");
+    let mut n = n;
+    let mut factors = vec![];
+    let mut factor = two;
+    // factors only grow while n shrinks, so the square can not overflow before passing n
+    while factor.checked_mul(factor).is_some_and(|square| square <= n) {
+        let mut power = 0;
+        while n % factor == T::ZERO {
+            n = n / factor;
+            power += 1;
+        }
+        if power > 0 {
+            factors.push((factor, power));
+        }
+        factor = factor.checked_add(T::ONE).expect("factor is below n");
+    }
+    if n >= two {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+// gcd with x and y such that a*x + b*y = gcd, none on overflow
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r.checked_sub(quotient.checked_mul(r)?)?);
+        (old_x, x) = (x, old_x.checked_sub(quotient.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(quotient.checked_mul(y)?)?);
+    }
+    if old_r < T::ZERO {
+        let neg = |v: T| T::ZERO.checked_sub(v);
+        return Some((neg(old_r)?, neg(old_x)?, neg(old_y)?));
+    }
+    Some((old_r, old_x, old_y))
+}
+
+// none for a zero modulus
+fn rem_euclid<T: Integer>(a: T, m: T) -> Option<T> {
+    let r = a.checked_rem(m)?;
+    if r < T::ZERO {
+        r.checked_add(m.checked_abs()?)
+    } else {
+        Some(r)
+    }
+}
+
+// x in 0..m with a*x = 1 (mod m), none if a and m are not coprime or m is zero
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    if m == T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a, m)?;
+    if g != T::ONE {
+        return None;
+    }
+    rem_euclid(x, m)
+}
+
+// smallest non-negative x with x = residue (mod modulus) for every pair, with the positive lcm of the
+// moduli, whose signs do not matter, none if the congruences contradict each other, a modulus is zero
+// or on overflow
+pub fn crt<T: Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences.into_iter().try_fold((T::ZERO, T::ONE), |(x, m), (residue, modulus)| {
+        let modulus = modulus.checked_abs()?;
+        if modulus == T::ZERO {
+            return None;
+        }
+        // x + m*k = residue (mod modulus), solved for k
+        let (g, p, _) = extended_gcd(m, modulus)?;
+        let diff = residue.checked_sub(x)?;
+        if diff.checked_rem(g)? != T::ZERO {
+            return None;
+        }
+        let step = modulus.checked_div(g)?;
+        let k = rem_euclid(rem_euclid(diff.checked_div(g)?, step)?.checked_mul(rem_euclid(p, step)?)?, step)?;
+        let combined = m.checked_mul(step)?;
+        Some((rem_euclid(x.checked_add(m.checked_mul(k)?)?, combined)?, combined))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{crt, extended_gcd, gcd, lcm, lcm_all, mod_inverse, prime_factors, rem_euclid};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(99, vec![(3, 2), (11, 1)])]
+    #[case(121, vec![(11, 2)])]
+    #[case(128, vec![(2, 7)])]
+    #[case(97, vec![(97, 1)])]
+    #[case(1, vec![])]
+    fn test_prime_factors(#[case] x: u64, #[case] expected: Vec<(u64, u32)>) {
+        assert_eq!(prime_factors(x), expected)
+    }
+
+    #[test]
+    fn factors_near_type_limits() {
+        assert_eq!(prime_factors(u8::MAX), [(3, 1), (5, 1), (17, 1)]);
+        assert_eq!(prime_factors(251u8), [(251, 1)]);
+        assert_eq!(prime_factors(i64::MAX), [(7, 2), (73, 1), (127, 1), (337, 1), (92737, 1), (649657, 1)]);
+    }
+
+    #[rstest]
+    #[case(99, 121, 1089)]
+    #[case(4, 5, 20)]
+    #[case(128, 64, 128)]
+    #[case(0, 5, 0)]
+    fn test_lcm(#[case] x: u64, #[case] y: u64, #[case] expected: u64) {
+        assert_eq!(lcm(x, y), Some(expected))
+    }
+
+    #[test]
+    fn checks_overflow() {
+        assert_eq!(gcd(-12i32, 18), Some(6));
+        assert_eq!(gcd(i32::MIN, 0), None);
+        assert_eq!(gcd(i32::MIN, -1), None);
+        assert_eq!(extended_gcd(i64::MIN, -1), None);
+        assert_eq!(lcm(-4i64, 6), Some(12));
+        assert_eq!(lcm(200u8, 3), None);
+        assert_eq!(lcm_all([2u32, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u32>::new()), Some(1));
+        assert_eq!(lcm_all([1u64 << 40, 3 << 30, 5 << 20, 7]), Some(105 << 40));
+        assert_eq!(lcm_all([1u64 << 40, 3, 5, 7, 11, 13, 17, 19, 23, 29]), None);
+    }
+
+    #[rstest]
+    #[case(240, 46)]
+    #[case(-240, 46)]
+    #[case(17, -5)]
+    #[case(0, 7)]
+    fn test_extended_gcd(#[case] a: i64, #[case] b: i64) {
+        let (g, x, y) = extended_gcd(a, b).unwrap();
+        assert_eq!(Some(g), gcd(a, b));
+        assert_eq!(a * x + b * y, g);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(6i64, 9), None);
+        assert_eq!(mod_inverse(1i64, 0), None);
+        assert_eq!(rem_euclid(-7i64, 3), Some(2));
+        assert_eq!(rem_euclid(1i64, 0), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // moduli need not be coprime, as long as they agree
+        assert_eq!(crt([(3i64, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt(Vec::<(i32, i32)>::new()), Some((0, 1)));
+        assert_eq!(crt([(0i64, 0)]), None);
+        assert_eq!(crt([(2i64, 3), (0, 0)]), None);
+        // negative moduli give the same congruences as positive ones
+        assert_eq!(crt([(2i64, -3)]), Some((2, 3)));
+        assert_eq!(crt([(2i64, -3), (3, 5), (-5, -7)]), Some((23, 105)));
+        assert_eq!(crt([(0i64, i64::MIN)]), None);
+    }
+}
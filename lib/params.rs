@@ -0,0 +1,176 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ParamError {
+    #[error("unknown parameter '{name}', known are: {known}")]
+    Unknown { name: String, known: String },
+    #[error("invalid value '{value}' of parameter '{name}': {reason}")]
+    Invalid { name: String, value: String, reason: String },
+    #[error("expected a parameter as name=value, got '{0}'")]
+    Syntax(String),
+    #[error("reading parameters {}: {source}", .path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("parsing parameters {}: {reason}", .path.display())]
+    Parse { path: PathBuf, reason: String },
+}
+
+// value of a parameter, set from and shown as text
+pub trait ParamValue {
+    fn set(&mut self, value: &str) -> Result<(), String>;
+
+    fn get(&self) -> String;
+}
+
+impl<T: FromStr + Display> ParamValue for T
+where
+    T::Err: Display,
+{
+    fn set(&mut self, value: &str) -> Result<(), String> {
+        *self = value.parse().map_err(|err: T::Err| err.to_string())?;
+        Ok(())
+    }
+
+    fn get(&self) -> String {
+        self.to_string()
+    }
+}
+
+// named parameters of a solution, borrowing the fields holding them
+#[derive(Default)]
+pub struct Params<'a> {
+    params: Vec<(&'static str, &'a mut dyn ParamValue)>,
+}
+
+impl<'a> Params<'a> {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn with(mut self, name: &'static str, value: &'a mut dyn ParamValue) -> Self {
+        self.params.push((name, value));
+        self
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        let Some((_, param)) = self.params.iter_mut().find(|(n, _)| *n == name) else {
+            return Err(ParamError::Unknown {
+                name: name.to_string(),
+                known: if self.params.is_empty() {
+                    "none".to_string()
+                } else {
+                    self.params.iter().map(|(n, _)| *n).collect::<Vec<_>>().join(", ")
+                },
+            });
+        };
+        param.set(value).map_err(|reason| ParamError::Invalid {
+            name: name.to_string(),
+            value: value.to_string(),
+            reason,
+        })
+    }
+
+    // names and current values
+    pub fn values(&self) -> Vec<(&'static str, String)> {
+        self.params.iter().map(|(name, param)| (*name, param.get())).collect()
+    }
+}
+
+// splits "rows=7" into its name and value
+pub fn parse_assignment(s: &str) -> Result<(String, String), ParamError> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_string(), value.trim().to_string())),
+        _ => Err(ParamError::Syntax(s.to_string())),
+    }
+}
+
+// parameters of a fixture, a TOML file of `name = value` lines
+pub fn load_fixture(path: &Path) -> Result<Vec<(String, String)>, ParamError> {
+    let content = fs::read_to_string(path).map_err(|source| ParamError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse_fixture(&content).map_err(|reason| ParamError::Parse {
+        path: path.to_path_buf(),
+        reason,
+    })
+}
+
+fn parse_fixture(content: &str) -> Result<Vec<(String, String)>, String> {
+    let table: toml::Table = toml::from_str(content).map_err(|err| err.to_string())?;
+    table
+        .into_iter()
+        .map(|(name, value)| match value {
+            toml::Value::String(s) => Ok((name, s)),
+            toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+                Ok((name, value.to_string()))
+            }
+            _ => Err(format!("parameter '{name}' is not a string, number or boolean")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::{parse_assignment, parse_fixture, ParamError, Params};
+
+    struct Grid {
+        rows: usize,
+        wrap: bool,
+    }
+
+    impl Grid {
+        fn params(&mut self) -> Params<'_> {
+            Params::new().with("rows", &mut self.rows).with("wrap", &mut self.wrap)
+        }
+    }
+
+    #[test]
+    fn sets_params_by_name() {
+        let mut grid = Grid { rows: 103, wrap: false };
+        let mut params = grid.params();
+        params.set("rows", "7").unwrap();
+        params.set("wrap", "true").unwrap();
+        assert_eq!(params.values(), [("rows", "7".to_string()), ("wrap", "true".to_string())]);
+        assert_eq!((grid.rows, grid.wrap), (7, true));
+    }
+
+    #[test]
+    fn rejects_unknown_and_invalid_params() {
+        let mut grid = Grid { rows: 103, wrap: false };
+        let mut params = grid.params();
+
+        let err = params.set("columns", "11").unwrap_err();
+        assert!(matches!(err, ParamError::Unknown { .. }));
+        assert!(err.to_string().ends_with("known are: rows, wrap"));
+
+        assert!(matches!(params.set("rows", "-1").unwrap_err(), ParamError::Invalid { .. }));
+        assert_eq!(grid.rows, 103);
+    }
+
+    #[test]
+    fn parses_assignments() {
+        assert_eq!(parse_assignment("rows=7").unwrap(), ("rows".to_string(), "7".to_string()));
+        assert_eq!(parse_assignment(" area = 7 ").unwrap(), ("area".to_string(), "7".to_string()));
+        assert!(matches!(parse_assignment("rows").unwrap_err(), ParamError::Syntax(_)));
+        assert!(matches!(parse_assignment("=7").unwrap_err(), ParamError::Syntax(_)));
+    }
+
+    #[test]
+    fn parses_fixture() {
+        let params = parse_fixture("rows = 7\ncolumns = 11\nmin = 7.5\nname = \"example\"\n").unwrap();
+        assert_eq!(
+            params,
+            [
+                ("columns".to_string(), "11".to_string()),
+                ("min".to_string(), "7.5".to_string()),
+                ("name".to_string(), "example".to_string()),
+                ("rows".to_string(), "7".to_string()),
+            ]
+        );
+        assert!(parse_fixture("rows = [7]\n").is_err());
+    }
+}
@@ -0,0 +1,524 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+pub struct Path<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+    pub visited: HashSet<S>,
+}
+
+// every state lying on some optimal path, linked to all of its equal-cost predecessors
+pub struct ShortestPaths<S, C> {
+    pub cost: C,
+    nodes: Vec<Node<S, C>>,
+    indices: HashMap<S, usize>,
+    goals: Vec<usize>,
+}
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    predecessors: Vec<usize>,
+    visited: bool,
+}
+
+struct Search<S, C> {
+    nodes: Vec<Node<S, C>>,
+    indices: HashMap<S, usize>,
+    goals: Vec<usize>,
+}
+
+pub fn dijkstra<S, C, IS, FS, IN, FG>(
+    starts: IS,
+    successors: FS,
+    is_goal: FG,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    IS: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+// heuristic must never overestimate the remaining cost, and must be consistent
+// (h(a) <= cost(a, b) + h(b)), as visited states are never reopened
+pub fn astar<S, C, IS, FS, IN, FH, FG>(
+    starts: IS,
+    successors: FS,
+    heuristic: FH,
+    is_goal: FG,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    IS: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let search = search(starts, successors, heuristic, is_goal, false);
+    let &goal = search.goals.first()?;
+
+    let mut path = vec![search.nodes[goal].state.clone()];
+    let mut index = goal;
+    while let Some(&parent) = search.nodes[index].predecessors.first() {
+        path.push(search.nodes[parent].state.clone());
+        index = parent;
+    }
+    path.reverse();
+
+    Some(Path {
+        cost: search.nodes[goal].cost,
+        path,
+        visited: search
+            .nodes
+            .into_iter()
+            .filter(|node| node.visited)
+            .map(|node| node.state)
+            .collect(),
+    })
+}
+
+pub fn dijkstra_all<S, C, IS, FS, IN, FG>(
+    starts: IS,
+    successors: FS,
+    is_goal: FG,
+) -> Option<ShortestPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    IS: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar_all(starts, successors, |_| C::default(), is_goal)
+}
+
+// same as astar, but keeps searching until all goal states reachable with the optimal cost are
+// found, recording every equal-cost predecessor along the way
+pub fn astar_all<S, C, IS, FS, IN, FH, FG>(
+    starts: IS,
+    successors: FS,
+    heuristic: FH,
+    is_goal: FG,
+) -> Option<ShortestPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    IS: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let search = search(starts, successors, heuristic, is_goal, true);
+    let &goal = search.goals.first()?;
+
+    Some(ShortestPaths {
+        cost: search.nodes[goal].cost,
+        nodes: search.nodes,
+        indices: search.indices,
+        goals: search.goals,
+    })
+}
+
+fn search<S, C, IS, FS, IN, FH, FG>(
+    starts: IS,
+    mut successors: FS,
+    mut heuristic: FH,
+    mut is_goal: FG,
+    all: bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    IS: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut nodes: Vec<Node<S, C>> = Vec::new();
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut goals: Vec<usize> = Vec::new();
+    let mut queue: BinaryHeap<Reverse<(C, usize)>> = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start) {
+            let index = nodes.len();
+            queue.push(Reverse((heuristic(entry.key()), index)));
+            nodes.push(Node {
+                state: entry.key().clone(),
+                cost: C::default(),
+                predecessors: Vec::new(),
+                visited: false,
+            });
+            entry.insert(index);
+        }
+    }
+
+    while let Some(Reverse((estimate, index))) = queue.pop() {
+        if let Some(&goal) = goals.first() {
+            if estimate > nodes[goal].cost {
+                break;
+            }
+        }
+
+        let node = &mut nodes[index];
+        if node.visited {
+            continue;
+        }
+        node.visited = true;
+        let cost = node.cost;
+
+        if is_goal(&node.state) {
+            goals.push(index);
+            if !all {
+                break;
+            }
+            continue;
+        }
+
+        for (next, step_cost) in successors(&node.state) {
+            let next_cost = cost + step_cost;
+            match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    let next_node = &mut nodes[next_index];
+                    // start states keep no predecessors, so that every path begins in one
+                    if all && next_cost == next_node.cost && !next_node.predecessors.is_empty() {
+                        next_node.predecessors.push(index);
+                        continue;
+                    }
+                    if next_node.visited || next_cost >= next_node.cost {
+                        continue;
+                    }
+                    next_node.cost = next_cost;
+                    next_node.predecessors = vec![index];
+                    let estimate = next_cost + heuristic(&next_node.state);
+                    queue.push(Reverse((estimate, next_index)));
+                }
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    let estimate = next_cost + heuristic(entry.key());
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        cost: next_cost,
+                        predecessors: vec![index],
+                        visited: false,
+                    });
+                    entry.insert(next_index);
+                    queue.push(Reverse((estimate, next_index)));
+                }
+            }
+        }
+    }
+
+    Search {
+        nodes,
+        indices,
+        goals,
+    }
+}
+
+impl<S: Clone + Eq + Hash, C> ShortestPaths<S, C> {
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&index| &self.nodes[index].state)
+    }
+
+    pub fn visited(&self) -> impl Iterator<Item = &S> {
+        self.nodes
+            .iter()
+            .filter(|node| node.visited)
+            .map(|node| &node.state)
+    }
+
+    // predecessors of a state on any optimal path to it
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        self.indices
+            .get(state)
+            .map(|&index| self.nodes[index].predecessors.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|&index| &self.nodes[index].state)
+    }
+
+    // states lying on at least one optimal path to some goal
+    pub fn states(&self) -> HashSet<S> {
+        self.union(|state| state.clone())
+    }
+
+    pub fn union<T: Eq + Hash, F: FnMut(&S) -> T>(&self, mut func: F) -> HashSet<T> {
+        self.on_paths()
+            .into_iter()
+            .map(|index| func(&self.nodes[index].state))
+            .collect()
+    }
+
+    // number of distinct optimal paths, zero cost cycles are not supported
+    pub fn count(&self) -> u64 {
+        let mut counts: Vec<Option<u64>> = vec![None; self.nodes.len()];
+        let mut stack = self.goals.clone();
+        while let Some(&index) = stack.last() {
+            if counts[index].is_some() {
+                stack.pop();
+                continue;
+            }
+            let predecessors = &self.nodes[index].predecessors;
+            let missing = predecessors
+                .iter()
+                .filter(|&&predecessor| counts[predecessor].is_none())
+                .copied()
+                .collect::<Vec<_>>();
+            if missing.is_empty() {
+                counts[index] = Some(if predecessors.is_empty() {
+                    1
+                } else {
+                    predecessors.iter().filter_map(|&predecessor| counts[predecessor]).sum()
+                });
+                stack.pop();
+            } else {
+                stack.extend(missing);
+            }
+        }
+        self.goals.iter().filter_map(|&goal| counts[goal]).sum()
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        PathsIterator {
+            paths: self,
+            goals: self.goals.iter(),
+            stack: Vec::new(),
+        }
+    }
+
+    fn on_paths(&self) -> Vec<usize> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut stack = self.goals.clone();
+        let mut result = Vec::new();
+        while let Some(index) = stack.pop() {
+            if seen[index] {
+                continue;
+            }
+            seen[index] = true;
+            result.push(index);
+            stack.extend(&self.nodes[index].predecessors);
+        }
+        result
+    }
+}
+
+struct PathsIterator<'a, S, C> {
+    paths: &'a ShortestPaths<S, C>,
+    goals: std::slice::Iter<'a, usize>,
+    // walks back from goal; each entry is a node and the position of its next predecessor to try
+    stack: Vec<(usize, usize)>,
+}
+
+impl<S: Clone, C> Iterator for PathsIterator<'_, S, C> {
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(&(index, next_predecessor)) = self.stack.last() else {
+                self.stack.push((*self.goals.next()?, 0));
+                continue;
+            };
+            let predecessors = &self.paths.nodes[index].predecessors;
+            if predecessors.is_empty() {
+                let path = self
+                    .stack
+                    .iter()
+                    .rev()
+                    .map(|&(index, _)| self.paths.nodes[index].state.clone())
+                    .collect();
+                self.stack.pop();
+                return Some(path);
+            }
+            if next_predecessor < predecessors.len() {
+                self.stack.last_mut().unwrap().1 += 1;
+                self.stack.push((predecessors[next_predecessor], 0));
+            } else {
+                self.stack.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Coord, Grid, DIRECTIONS_CARDINAL};
+    use crate::search::{astar, dijkstra, dijkstra_all};
+    use std::collections::HashSet;
+
+    fn maze() -> Grid<bool> {
+        Grid::from_lines_iter(
+            [
+                "..#....",
+                ".##.##.",
+                "....#..",
+                "#.#...#",
+                "..#.#..",
+            ]
+            .map(|line| line.chars().map(|c| c == '#')),
+        )
+        .unwrap()
+    }
+
+    fn neighbours(grid: &Grid<bool>, coord: Coord) -> impl Iterator<Item = (Coord, u64)> + '_ {
+        DIRECTIONS_CARDINAL
+            .into_iter()
+            .map(move |offset| coord + offset)
+            .filter(|&next| matches!(grid.get(next), Some(false)))
+            .map(|next| (next, 1))
+    }
+
+    #[test]
+    fn dijkstra_finds_shortest_path() {
+        let grid = maze();
+        let end = Coord { x: 6, y: 4 };
+        let result = dijkstra(
+            [Coord { x: 0, y: 0 }],
+            |&coord| neighbours(&grid, coord),
+            |&coord| coord == end,
+        )
+        .unwrap();
+        assert_eq!(result.cost, 10);
+        assert_eq!(result.path.len(), 11);
+        assert_eq!(result.path.first(), Some(&Coord { x: 0, y: 0 }));
+        assert_eq!(result.path.last(), Some(&end));
+        for (a, b) in result.path.iter().zip(result.path.iter().skip(1)) {
+            assert_eq!(a.x.abs_diff(b.x) + a.y.abs_diff(b.y), 1);
+        }
+        assert!(result.visited.contains(&end));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = maze();
+        let end = Coord { x: 6, y: 4 };
+        let result = astar(
+            [Coord { x: 0, y: 0 }],
+            |&coord| neighbours(&grid, coord),
+            |&coord| coord.x.abs_diff(end.x) + coord.y.abs_diff(end.y),
+            |&coord| coord == end,
+        )
+        .unwrap();
+        assert_eq!(result.cost, 10);
+        assert!(result.visited.len() <= grid.iter().filter(|(_, &wall)| !wall).count());
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let grid = maze();
+        let result = dijkstra(
+            [Coord { x: 0, y: 0 }],
+            |&coord| neighbours(&grid, coord),
+            |&coord| coord == Coord { x: 2, y: 0 },
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn weighted_direction_aware_state() {
+        // turning costs more than moving, so the path with fewest turns wins
+        let grid = Grid::<bool>::new(4, 4);
+        let end = Coord { x: 3, y: 3 };
+        let result = dijkstra(
+            [(Coord { x: 0, y: 0 }, DIRECTIONS_CARDINAL[0])],
+            |&(coord, offset)| {
+                let mut next = vec![
+                    ((coord, offset.rotate_left()), 10),
+                    ((coord, offset.rotate_right()), 10),
+                ];
+                if grid.is_valid(coord + offset) {
+                    next.push(((coord + offset, offset), 1));
+                }
+                next
+            },
+            |&(coord, _)| coord == end,
+        )
+        .unwrap();
+        assert_eq!(result.cost, 16);
+    }
+
+    #[test]
+    fn all_shortest_paths_in_open_grid() {
+        // every monotone path is optimal, there are binomial(4, 2) of them
+        let grid = Grid::<bool>::new(3, 3);
+        let end = Coord { x: 2, y: 2 };
+        let result = dijkstra_all(
+            [Coord { x: 0, y: 0 }],
+            |&coord| neighbours(&grid, coord),
+            |&coord| coord == end,
+        )
+        .unwrap();
+        assert_eq!(result.cost, 4);
+        assert_eq!(result.count(), 6);
+        assert_eq!(result.states().len(), 9);
+
+        let paths: HashSet<Vec<Coord>> = result.paths().collect();
+        assert_eq!(paths.len(), 6);
+        for path in &paths {
+            assert_eq!(path.len(), 5);
+            assert_eq!(path.first(), Some(&Coord { x: 0, y: 0 }));
+            assert_eq!(path.last(), Some(&end));
+        }
+        assert_eq!(result.predecessors(&end).count(), 2);
+    }
+
+    #[test]
+    fn all_shortest_paths_with_multiple_goals() {
+        let grid = maze();
+        let result = dijkstra_all(
+            [Coord { x: 0, y: 0 }],
+            |&coord| neighbours(&grid, coord),
+            |&coord| coord.y == 2 && coord.x >= 2,
+        )
+        .unwrap();
+        assert_eq!(result.cost, 4);
+        assert_eq!(result.goals().collect::<Vec<_>>(), vec![&Coord { x: 2, y: 2 }]);
+        assert_eq!(result.count(), 1);
+        assert_eq!(
+            result.union(|coord| coord.y),
+            HashSet::from([0, 1, 2]),
+        );
+    }
+
+    #[test]
+    fn all_shortest_paths_direction_aware() {
+        // union of all optimal paths projected to coordinates only
+        let grid = Grid::<bool>::new(3, 3);
+        let end = Coord { x: 2, y: 2 };
+        let result = dijkstra_all(
+            [
+                (Coord { x: 0, y: 0 }, DIRECTIONS_CARDINAL[0]),
+                (Coord { x: 0, y: 0 }, DIRECTIONS_CARDINAL[1]),
+            ],
+            |&(coord, offset)| {
+                let mut next = vec![
+                    ((coord, offset.rotate_left()), 10),
+                    ((coord, offset.rotate_right()), 10),
+                ];
+                if grid.is_valid(coord + offset) {
+                    next.push(((coord + offset, offset), 1));
+                }
+                next
+            },
+            |&(coord, _)| coord == end,
+        )
+        .unwrap();
+        assert_eq!(result.cost, 14);
+        assert_eq!(result.count(), 2);
+        assert_eq!(result.union(|&(coord, _)| coord).len(), 8);
+    }
+}
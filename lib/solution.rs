@@ -0,0 +1,169 @@
+use crate::params::Params;
+use anyhow::Result;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(x) => write!(f, "{x}"),
+            Answer::Signed(x) => write!(f, "{x}"),
+            Answer::Text(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+// integer answers are equal by value, whatever their signedness
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Signed(b)) | (Answer::Signed(b), Answer::Unsigned(a)) => {
+                i128::from(*a) == i128::from(*b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    // parameters differing between the examples and the real input, the defaults being those of the real input
+    fn params(&mut self) -> Params<'_> {
+        Params::new()
+    }
+}
+
+// object safe counterpart of Solution, so that differently typed solutions can be kept together
+pub trait DynSolution: Sync {
+    fn parse_dyn<'a>(&'a self, input: &str) -> Result<Box<dyn Parsed + 'a>>;
+
+    fn params_dyn(&mut self) -> Params<'_>;
+}
+
+pub trait Parsed {
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+}
+
+struct ParsedInput<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn part1(&self) -> Result<Answer> {
+        self.solution.part1(&self.input)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        self.solution.part2(&self.input)
+    }
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn parse_dyn<'a>(&'a self, input: &str) -> Result<Box<dyn Parsed + 'a>> {
+        Ok(Box::new(ParsedInput {
+            solution: self,
+            input: self.parse(input)?,
+        }))
+    }
+
+    fn params_dyn(&mut self) -> Params<'_> {
+        self.params()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{Answer, DynSolution, Solution};
+    use anyhow::Result;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Vec<i64>> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(&self, input: &Vec<i64>) -> Result<Answer> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(&self, input: &Vec<i64>) -> Result<Answer> {
+            Ok(input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",").into())
+        }
+    }
+
+    #[test]
+    fn dyn_solution_runs_both_parts() {
+        let solution: &dyn DynSolution = &Sum;
+        let parsed = solution.parse_dyn("1\n-3\n5\n").unwrap();
+        assert_eq!(parsed.part1().unwrap(), Answer::Signed(3));
+        assert_eq!(parsed.part2().unwrap(), Answer::Text("1,-3,5".to_string()));
+    }
+
+    #[test]
+    fn dyn_solution_reports_parse_errors() {
+        assert!(Sum.parse_dyn("1\nx\n").is_err());
+    }
+
+    #[test]
+    fn answers_display_plainly() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-7_i32).to_string(), "-7");
+        assert_eq!(Answer::from("2,8").to_string(), "2,8");
+    }
+
+    #[test]
+    fn integer_answers_equal_by_value() {
+        assert_eq!(Answer::from(42_u64), Answer::from(42_i32));
+        assert_ne!(Answer::from(-1_i64), Answer::from(u64::MAX));
+        assert_ne!(Answer::from(42_u64), Answer::from("42"));
+    }
+}